@@ -4,7 +4,7 @@ use eframe::{
 };
 use egui::{Align2, RichText};
 use egui_extras::{Column, TableBuilder};
-use egui_notify::{ToastLevel, Toasts};
+use egui_notify::{Toast, ToastLevel, Toasts};
 use std::time::Duration;
 
 const DEFAULT_DURATION: u64 = 3500;
@@ -25,6 +25,10 @@ impl App for ExampleApp {
                     ui.heading("caption");
                     ui.text_edit_multiline(&mut self.caption);
                 });
+                ui.group(|ui| {
+                    ui.heading("inline");
+                    ui.add(&Toast::basic(self.caption.clone()).info());
+                });
                 ui.group(|ui| {
                     ui.heading("options");
                     let mut expires = self.toasts.default_options.duration.is_some();
@@ -78,11 +82,12 @@ impl App for ExampleApp {
                                     });
                                     row.col(|ui| {
                                         ui.add_enabled_ui(expires, |ui| {
-                                            if let Some(duration) =
-                                                self.toasts.default_options.duration.as_mut()
-                                            {
-                                                ui.add(Slider::new(&mut duration.0, 1.0..=10.0));
-                                                duration.1 = duration.0;
+                                            let options = &mut self.toasts.default_options;
+                                            if let Some(mut secs) = options.duration_secs() {
+                                                let slider = Slider::new(&mut secs, 1.0..=10.0);
+                                                if ui.add(slider).changed() {
+                                                    options.set_duration_secs(secs);
+                                                }
                                             };
                                         });
                                     });
@@ -149,6 +154,12 @@ impl App for ExampleApp {
                     if ui.button(color("error", ToastLevel::Error)).clicked() {
                         self.toasts.error(self.caption.clone());
                     }
+                    if ui.button(color("debug", ToastLevel::Debug)).clicked() {
+                        self.toasts.debug(self.caption.clone());
+                    }
+                    if ui.button(color("trace", ToastLevel::Trace)).clicked() {
+                        self.toasts.trace(self.caption.clone());
+                    }
                     if ui.button("basic").clicked() {
                         self.toasts.basic(self.caption.clone());
                     }
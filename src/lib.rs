@@ -3,31 +3,59 @@
 
 #![warn(missing_docs)]
 
+#[cfg(feature = "log")]
+mod logger;
 mod toast;
-use crossbeam_channel::TryRecvError;
+use crossbeam_channel::{Receiver, TryRecvError};
+#[cfg(feature = "log")]
+pub use logger::*;
 pub use toast::*;
 
+/// Re-exported so the `egui` version used by this crate can be referenced directly.
+pub use egui;
 #[doc(hidden)]
 pub use egui::__run_test_ctx;
 use egui::{
-    epaint::Shadow, pos2, vec2, Align2, Color32, Context, FontId, Id, LayerId, Order, Pos2, Rect,
-    Rounding, Stroke, Vec2,
+    epaint::Shadow, pos2, style::WidgetVisuals, Align, Area, Context, CursorIcon, FontId, Id,
+    LayerId, Painter, Pos2, Rect, Response, Sense, Stroke, TextEdit, Ui, Widget,
 };
+/// `egui` types used in this crate's public API.
+pub use egui::{vec2, Align2, Color32, Galley, Margin, Order, Rounding, Vec2};
+use std::{
+    cmp::Reverse,
+    fmt::Display,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use unicode_segmentation::UnicodeSegmentation;
 
 pub(crate) const TOAST_WIDTH: f32 = 180.;
 pub(crate) const TOAST_HEIGHT: f32 = 34.;
 
+const DEFAULT_PADDING: Vec2 = vec2(10., 10.);
+const PROGRESS_SMOOTHING: f32 = 10.;
+const COMPRESSED_SPACING_FACTOR: f32 = 0.75;
+const POP_SCALE: f32 = 0.8;
+const DEFAULT_ANIMATION_DURATION: f32 = 0.25;
+const MAX_ANIMATION_DT: f32 = 0.1;
+const CONTAINER_MARGIN: f32 = 6.;
+const HEADER_BUTTON_GAP: f32 = 16.;
+const TAB_REPLACEMENT: &str = "    ";
+
 const ERROR_COLOR: Color32 = Color32::from_rgb(200, 90, 90);
 const INFO_COLOR: Color32 = Color32::from_rgb(150, 200, 210);
 const WARNING_COLOR: Color32 = Color32::from_rgb(230, 220, 140);
 const SUCCESS_COLOR: Color32 = Color32::from_rgb(140, 230, 140);
+const DEBUG_COLOR: Color32 = Color32::from_rgb(170, 150, 210);
+const TRACE_COLOR: Color32 = Color32::from_rgb(130, 150, 170);
 
-/// Load icon font
+/// Load icon font. Does nothing without the `phosphor` feature.
+#[cfg(feature = "phosphor")]
 pub fn load_icon_font(ctx: &Context) {
     let mut fonts = egui::FontDefinitions::default();
     egui_phosphor::add_to_fonts(&mut fonts, egui_phosphor::Variant::Regular);
 
-    let mut phosphor_data = fonts.font_data.get_mut("phosphor").unwrap();
+    let phosphor_data = fonts.font_data.get_mut("phosphor").unwrap();
     phosphor_data.tweak = egui::FontTweak {
         y_offset: 1.25,
         ..Default::default()
@@ -35,6 +63,10 @@ pub fn load_icon_font(ctx: &Context) {
     ctx.set_fonts(fonts);
 }
 
+/// Load icon font. Does nothing without the `phosphor` feature.
+#[cfg(not(feature = "phosphor"))]
+pub fn load_icon_font(_ctx: &Context) {}
+
 /// Main notifications collector.
 /// # Usage
 /// You need to create [`Toasts`] once and call `.show(ctx)` in every frame.
@@ -49,19 +81,248 @@ pub fn load_icon_font(ctx: &Context) {
 /// t.show(ctx);
 /// # });
 /// ```
+///
+/// [`Toasts`] is [`Send`], so it can be stored in application state that moves between threads.
+/// Any callbacks stored on toasts are required to be [`Send`] as well.
 pub struct Toasts {
     /// The attachment point for toasts
     pub anchor: Align2,
     /// Default toast options.
     pub default_options: ToastOptions,
     toasts: Vec<Toast>,
+    id: Id,
+    channels: Vec<(String, Toasts)>,
     margin: Vec2,
+    safe_area: Margin,
     spacing: f32,
+    spacing_mode: SpacingMode,
     padding: Vec2,
+    level_colors: LevelColors,
     reverse: bool,
-    speed: f32,
+    paint_order: PaintOrder,
+    animation: AnimationKind,
+    exit_animation: Option<AnimationKind>,
+    container_background: Option<Color32>,
+    header: Option<String>,
+    duration_counting: DurationCounting,
+    full_width: bool,
+    muted: bool,
+    muted_toasts: Vec<Toast>,
+    max_total: Option<usize>,
+    max_visible: Option<usize>,
+    notify_hook: Option<NotifyHook>,
+    notify_interval: Duration,
+    last_notify: Option<f64>,
+    order: Order,
+    appear_duration: f32,
+    disappear_duration: f32,
+    smooth_progress: bool,
+    hover_info: bool,
+    dismiss_grace: Option<f32>,
+    selectable_text: bool,
+    reserve_space: bool,
+    suppress_initial_animation: bool,
+    suppress_consecutive_duplicates: bool,
+
+    pressed: Option<Id>,
+    shown: bool,
+    last_time: Option<f64>,
+    input_time: f64,
+    add_count: usize,
+    stats: ToastStats,
+    hovered_region: Option<ToastRegion>,
+    clicked_actions: Vec<(u128, usize)>,
+    banner: Option<Toast>,
+    collect_dismissed: bool,
+    dismissed: Vec<Toast>,
+    incoming: Option<Receiver<(ToastLevel, String)>>,
+    #[cfg(any(test, feature = "testing"))]
+    painted: Vec<PaintedLayout>,
+}
+
+type NotifyHook = Box<dyn FnMut(&Toast) + Send>;
+
+/// Region of a toast that can be hovered, see [`Toasts::hovered_region`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastRegion {
+    /// The toast itself, outside of any interactive region.
+    Body,
+    /// The closing cross.
+    Cross,
+    /// The action button with the given id, see [`Toast::add_action`].
+    Action(usize),
+}
+
+/// Order in which toasts are painted, deciding which one is on top when they overlap.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaintOrder {
+    /// Toasts are painted in stack order, the one furthest from the anchor on top.
+    #[default]
+    Stack,
+    /// The most recently added toast is painted on top.
+    NewestOnTop,
+    /// The oldest toast is painted on top.
+    OldestOnTop,
+}
+
+/// How toasts animate when appearing and disappearing.
+#[derive(Default, Debug, Clone, Copy)]
+pub enum AnimationKind {
+    /// Toasts slide in from the side of the anchor.
+    #[default]
+    Slide,
+    /// Toasts slide in from the side opposite of the anchor.
+    SlideOpposite,
+    /// Toasts scale up in place while fading in.
+    Pop,
+    /// Toasts fade in place.
+    Fade,
+    /// Toasts grow in place from nothing.
+    Shrink,
+    /// Toasts are transformed by the hook, called every frame with the toast and its
+    /// animation value, from `0.0` when hidden to `1.0` when fully shown.
+    Custom(fn(&Toast, f32) -> ToastTransform),
+}
+
+impl AnimationKind {
+    /// Transform of `toast` at its current animation value, `side` being the anchor's.
+    fn transform(self, toast: &Toast, side: f32) -> ToastTransform {
+        let eased = ease_in_cubic(toast.value);
+        match self {
+            Self::Slide | Self::SlideOpposite => {
+                let side = if matches!(self, Self::Slide) {
+                    side
+                } else {
+                    -side
+                };
+                ToastTransform {
+                    offset: vec2(toast.width * (1. - eased) * side, 0.),
+                    ..Default::default()
+                }
+            }
+            Self::Pop => ToastTransform {
+                scale: POP_SCALE + (1. - POP_SCALE) * eased,
+                opacity: eased,
+                ..Default::default()
+            },
+            Self::Fade => ToastTransform {
+                opacity: eased,
+                ..Default::default()
+            },
+            Self::Shrink => ToastTransform {
+                scale: eased,
+                ..Default::default()
+            },
+            Self::Custom(hook) => hook(toast, toast.value),
+        }
+    }
+}
+
+/// Transform applied to a toast at its resting place in the stack, see [`AnimationKind::Custom`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToastTransform {
+    /// Offset from the resting place.
+    pub offset: Vec2,
+    /// Scale around the toast's center.
+    pub scale: f32,
+    /// Opacity everything is painted with.
+    pub opacity: f32,
+}
+
+impl Default for ToastTransform {
+    fn default() -> Self {
+        Self {
+            offset: Vec2::ZERO,
+            scale: 1.,
+            opacity: 1.,
+        }
+    }
+}
+
+/// When a toast's duration counts down.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationCounting {
+    /// Only once the toast has fully appeared, so animations extend its lifetime.
+    #[default]
+    IdleOnly,
+    /// As soon as the toast starts appearing, so the duration includes the appear animation.
+    FromAppear,
+}
+
+/// How spacing between adjacent toasts advances along the stack.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpacingMode {
+    /// Every gap uses the full spacing.
+    #[default]
+    Uniform,
+    /// Gaps shrink the further they are from the anchor, fitting more toasts on screen.
+    Compressed,
+}
 
-    held: bool,
+impl SpacingMode {
+    fn spacing(&self, spacing: f32, index: usize) -> f32 {
+        match self {
+            Self::Uniform => spacing,
+            Self::Compressed => spacing * COMPRESSED_SPACING_FACTOR.powi(index as i32),
+        }
+    }
+}
+
+/// Toasts taken by [`Toasts::take_snapshot`], to be put back with [`Toasts::restore_snapshot`].
+#[derive(Debug)]
+pub struct ToastsSnapshot {
+    toasts: Vec<Toast>,
+}
+
+impl ToastsSnapshot {
+    /// Number of toasts in the snapshot.
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Whether the snapshot has no toasts.
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+/// Counts gathered during the last [`Toasts::show`] call, see [`Toasts::debug_stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ToastStats {
+    /// Number of toasts in the collection.
+    pub total: usize,
+    /// Number of toasts painted.
+    pub visible: usize,
+    /// Number of toasts playing their disappear animation.
+    pub disappearing: usize,
+    /// Number of toasts still waiting on channel updates.
+    pub pending: usize,
+    /// Number of toasts waiting for room under the visible cap, see
+    /// [`Toasts::with_max_visible`].
+    pub queued: usize,
+    /// Number of galleys laid out.
+    pub galleys: usize,
+}
+
+/// Where the parts of a toast were painted during the last [`Toasts::show`] call,
+/// see [`Toasts::painted`].
+#[cfg(any(test, feature = "testing"))]
+#[derive(Debug, Clone)]
+pub struct PaintedLayout {
+    /// Index of the toast in [`Toasts::iter`].
+    pub index: usize,
+    /// The whole toast.
+    pub rect: Rect,
+    /// The caption.
+    pub caption: Rect,
+    /// Galley the caption was painted with.
+    pub caption_galley: Arc<Galley>,
+    /// The level icon, if any.
+    pub icon: Option<Rect>,
+    /// The closing cross, if painted.
+    pub cross: Option<Rect>,
+    /// The action buttons, in the order they were added.
+    pub actions: Vec<Rect>,
 }
 
 impl Toasts {
@@ -71,46 +332,568 @@ impl Toasts {
             default_options: ToastOptions::default(),
             anchor: Align2::RIGHT_BOTTOM,
             margin: vec2(8., 8.),
+            safe_area: Margin::ZERO,
             toasts: vec![],
+            id: Id::new("toasts"),
+            channels: vec![],
             spacing: 8.,
-            padding: vec2(10., 10.),
-            held: false,
-            speed: 4.,
+            spacing_mode: SpacingMode::Uniform,
+            padding: DEFAULT_PADDING,
+            level_colors: LevelColors::default(),
+            pressed: None,
+            shown: false,
+            last_time: None,
+            input_time: 0.,
+            add_count: 0,
+            stats: ToastStats::default(),
+            hovered_region: None,
+            clicked_actions: Vec::new(),
+            banner: None,
+            collect_dismissed: false,
+            dismissed: vec![],
+            incoming: None,
+            #[cfg(any(test, feature = "testing"))]
+            painted: vec![],
+            appear_duration: DEFAULT_ANIMATION_DURATION,
+            disappear_duration: DEFAULT_ANIMATION_DURATION,
             reverse: false,
+            paint_order: PaintOrder::Stack,
+            animation: AnimationKind::Slide,
+            exit_animation: None,
+            container_background: None,
+            header: None,
+            duration_counting: DurationCounting::IdleOnly,
+            full_width: false,
+            muted: false,
+            muted_toasts: vec![],
+            max_total: None,
+            max_visible: None,
+            notify_hook: None,
+            notify_interval: Duration::from_secs(1),
+            last_notify: None,
+            order: Order::Foreground,
+            smooth_progress: false,
+            hover_info: false,
+            dismiss_grace: None,
+            selectable_text: false,
+            reserve_space: true,
+            suppress_initial_animation: false,
+            suppress_consecutive_duplicates: false,
         }
     }
 
+    /// Toasts in the top right corner, the newest one nearest to the corner and painted on top.
+    /// The other corner presets work the same way.
+    /// ```
+    /// use egui::Align2;
+    /// use egui_notify::Toasts;
+    ///
+    /// for (mut t, anchor) in [
+    ///     (Toasts::top_right(), Align2::RIGHT_TOP),
+    ///     (Toasts::top_left(), Align2::LEFT_TOP),
+    ///     (Toasts::bottom_right(), Align2::RIGHT_BOTTOM),
+    ///     (Toasts::bottom_left(), Align2::LEFT_BOTTOM),
+    /// ] {
+    ///     assert_eq!(t.anchor, anchor);
+    ///     t.info("Old");
+    ///     t.info("New");
+    ///     let order: Vec<_> = t.iter().map(|toast| toast.caption()).collect();
+    ///     assert_eq!(order, ["New", "Old"]);
+    /// }
+    /// ```
+    pub fn top_right() -> Self {
+        Self::corner(Align2::RIGHT_TOP)
+    }
+
+    /// Toasts in the top left corner, see [`Toasts::top_right`].
+    pub fn top_left() -> Self {
+        Self::corner(Align2::LEFT_TOP)
+    }
+
+    /// Toasts in the bottom right corner, see [`Toasts::top_right`].
+    pub fn bottom_right() -> Self {
+        Self::corner(Align2::RIGHT_BOTTOM)
+    }
+
+    /// Toasts in the bottom left corner, see [`Toasts::top_right`].
+    pub fn bottom_left() -> Self {
+        Self::corner(Align2::LEFT_BOTTOM)
+    }
+
+    fn corner(anchor: Align2) -> Self {
+        Self::new()
+            .with_anchor(anchor)
+            .reverse(true)
+            .with_paint_order(PaintOrder::NewestOnTop)
+    }
+
     /// Adds new toast to the collection.
     /// By default adds toast at the end of the list, can be changed with `self.reverse`.
+    ///
+    /// If the toast has an id (see [`Toast::with_id`]) matching a toast that is still shown,
+    /// that toast is updated in place instead.
+    ///
+    /// The returned reference is always to the toast just added or updated.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// for reverse in [false, true] {
+    ///     let mut t = Toasts::default().reverse(reverse);
+    ///     t.info("First");
+    ///     assert_eq!(t.info("Second").caption(), "Second");
+    ///     let front = t.iter().next().unwrap().caption();
+    ///     assert_eq!(front, if reverse { "Second" } else { "First" });
+    /// }
+    /// ```
     pub fn add(&mut self, toast: Toast) -> &mut Toast {
-        if self.reverse {
-            self.toasts.insert(0, toast);
-            return self.toasts.get_mut(0).unwrap();
-        } else {
-            let add_index = self.toasts.len();
-            self.toasts.push(toast.with_add_index(add_index));
-            return self.toasts.get_mut(add_index).unwrap();
+        if self.muted {
+            let index = self.muted_toasts.len();
+            self.muted_toasts.push(toast);
+            return &mut self.muted_toasts[index];
+        }
+
+        if let Some(id) = toast.id {
+            if let Some(index) = self
+                .toasts
+                .iter()
+                .position(|t| t.id == Some(id) && !t.state.disappearing() && !t.state.disappeared())
+            {
+                let existing = &mut self.toasts[index];
+                existing.update_from(toast);
+                return existing;
+            }
+        }
+
+        if self.suppress_consecutive_duplicates {
+            let latest = self.add_count.wrapping_sub(1);
+            if let Some(index) = self.toasts.iter().position(|t| {
+                t.add_index == latest
+                    && !t.state.disappearing()
+                    && !t.state.disappeared()
+                    && t.level() == toast.level()
+                    && t.caption() == toast.caption()
+            }) {
+                return &mut self.toasts[index];
+            }
+        }
+
+        if let Some(max_total) = self.max_total {
+            self.evict_oldest(max_total.saturating_sub(1));
+        }
+
+        // Every toast gets a unique index so its animated position is tracked separately,
+        // letting neighbours slide smoothly when a toast is inserted in front of them.
+        let toast = toast.with_add_index(self.add_count);
+        self.add_count = self.add_count.wrapping_add(1);
+
+        if toast.options.notify {
+            self.notify(&toast);
+        }
+
+        let index = if self.reverse { 0 } else { self.toasts.len() };
+        self.toasts.insert(index, toast);
+        &mut self.toasts[index]
+    }
+
+    /// While muted, added toasts are held back instead of shown, e.g. for a focus mode.
+    /// Toasts already shown are left as they are. Unmuting releases the held back toasts.
+    /// [Channels](Toasts::channel) are muted and unmuted along with this collection.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default();
+    /// t.set_muted(true);
+    /// t.info("Later");
+    /// t.channel("chat").info("New message");
+    /// egui_notify::__run_test_ctx(|ctx| t.show(ctx));
+    /// assert_eq!(t.debug_stats().visible, 0);
+    /// assert_eq!(t.channel("chat").debug_stats().visible, 0);
+    ///
+    /// t.set_muted(false);
+    /// egui_notify::__run_test_ctx(|ctx| t.show(ctx));
+    /// assert_eq!(t.debug_stats().visible, 1);
+    /// assert_eq!(t.channel("chat").debug_stats().visible, 1);
+    /// ```
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+        if !muted {
+            self.flush_muted();
+        }
+        for (_, channel) in &mut self.channels {
+            channel.set_muted(muted);
+        }
+    }
+
+    /// Is adding toasts muted? See [`Toasts::set_muted`].
+    pub const fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    /// Shows the toasts held back while muted, even if still muted, in channels too.
+    pub fn flush_muted(&mut self) {
+        let muted = std::mem::replace(&mut self.muted, false);
+        for toast in std::mem::take(&mut self.muted_toasts) {
+            self.add(toast);
         }
+        self.muted = muted;
+        for (_, channel) in &mut self.channels {
+            channel.flush_muted();
+        }
+    }
+
+    /// Calls the notify hook, unless it was called less than the notify interval ago, going
+    /// by the input time of the last shown frame.
+    fn notify(&mut self, toast: &Toast) {
+        let Some(hook) = self.notify_hook.as_mut() else {
+            return;
+        };
+        let now = self.input_time;
+        if self
+            .last_notify
+            .is_some_and(|last| now - last < self.notify_interval.as_secs_f64())
+        {
+            return;
+        }
+        self.last_notify = Some(now);
+        hook(toast);
+    }
+
+    /// Dismisses the oldest toasts until at most `keep` toasts are left that aren't disappearing.
+    fn evict_oldest(&mut self, keep: usize) {
+        let mut active: Vec<_> = self
+            .toasts
+            .iter_mut()
+            .filter(|t| !t.state.disappearing() && !t.state.disappeared())
+            .collect();
+        if active.len() > keep {
+            active.sort_by_key(|t| t.add_index);
+            let excess = active.len() - keep;
+            for toast in active.into_iter().take(excess) {
+                toast.dismiss();
+            }
+        }
+    }
+
+    /// Iterates over the toasts in stack order, starting at the anchor.
+    ///
+    /// Time only advances through [`Toasts::show`], driven by the input time, so tests can
+    /// step it deterministically with [`egui::RawInput::predicted_dt`].
+    /// ```
+    /// use egui_notify::Toasts;
+    /// use std::time::Duration;
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut frame = |t: &mut Toasts, dt: f32| {
+    ///     let input = egui::RawInput { predicted_dt: dt, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    /// };
+    ///
+    /// let mut t = Toasts::default().with_collect_dismissed(true);
+    /// t.info("Short").set_duration(Some(Duration::from_secs(1)));
+    /// t.info("Long").set_duration(Some(Duration::from_secs(5)));
+    /// for _ in 0..30 {
+    ///     frame(&mut t, 0.1);
+    /// }
+    ///
+    /// let removed: Vec<_> = t
+    ///     .drain_dismissed()
+    ///     .into_iter()
+    ///     .map(|t| t.caption().to_owned())
+    ///     .collect();
+    /// assert_eq!(removed, ["Short"]);
+    /// assert_eq!(t.iter().map(|t| t.caption()).collect::<Vec<_>>(), ["Long"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+
+    /// Mutably iterates over the toasts in stack order, see [`Toasts::iter`].
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Toast> {
+        self.toasts.iter_mut()
+    }
+
+    /// Applies `f` to every toast, e.g. to extend all durations or make them all closable.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default();
+    /// t.error("Failed");
+    /// t.error("Failed again");
+    /// t.modify_all(|toast| {
+    ///     toast.set_closable(true);
+    /// });
+    /// assert!(t.iter().all(|toast| toast.closable()));
+    /// ```
+    pub fn modify_all(&mut self, f: impl FnMut(&mut Toast)) {
+        self.toasts.iter_mut().for_each(f);
+    }
+
+    /// Number of toasts in the collection, including ones still animating out.
+    pub fn len(&self) -> usize {
+        self.toasts.len()
     }
 
-    /// Dismisses the oldest toast
+    /// Is the collection empty?
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Dismisses the oldest toast that isn't disappearing yet.
+    /// Which toast is oldest doesn't depend on [`Toasts::reverse`]. With
+    /// [`Toasts::with_max_visible`] the oldest toast is a shown one, letting the next
+    /// waiting toast in once it's gone.
     pub fn dismiss_oldest_toast(&mut self) {
-        if let Some(toast) = self.toasts.get_mut(0) {
+        if let Some(toast) = self.active_toasts().min_by_key(|t| t.add_index) {
             toast.dismiss();
         }
     }
 
-    /// Dismisses the most recent toast
+    /// Dismisses the most recent toast that isn't disappearing yet.
+    /// Which toast is most recent doesn't depend on [`Toasts::reverse`]. A toast still
+    /// waiting under [`Toasts::with_max_visible`] is removed without ever being shown.
     pub fn dismiss_latest_toast(&mut self) {
-        if let Some(toast) = self.toasts.last_mut() {
+        if let Some(toast) = self.active_toasts().max_by_key(|t| t.add_index) {
             toast.dismiss();
         }
     }
 
-    /// Dismisses all toasts
-    pub fn dismiss_all_toasts(&mut self) {
+    fn active_toasts(&mut self) -> impl Iterator<Item = &mut Toast> {
+        self.toasts
+            .iter_mut()
+            .filter(|t| !t.state.disappearing() && !t.state.disappeared())
+    }
+
+    /// Reports whether the window `window_id` is open, dismissing the toasts pinned to it with
+    /// [`Toast::pin_to_window`] once it's closed. Meant to be called every frame with the
+    /// same `open` flag the window is shown with.
+    pub fn set_window_open(&mut self, window_id: Id, open: bool) {
+        if open {
+            return;
+        }
+        for toast in &mut self.toasts {
+            if toast.pinned_window == Some(window_id) {
+                toast.pinned_window = None;
+                toast.dismiss();
+            }
+        }
+        for (_, channel) in &mut self.channels {
+            channel.set_window_open(window_id, open);
+        }
+    }
+
+    /// Dismisses all toasts, returning how many started disappearing.
+    pub fn dismiss_all_toasts(&mut self) -> usize {
+        self.dismiss_where(|_| true)
+    }
+
+    /// Dismisses all toasts of the given level, returning how many started disappearing.
+    pub fn dismiss_level(&mut self, level: ToastLevel) -> usize {
+        self.dismiss_where(|toast| toast.level() == level)
+    }
+
+    /// Dismisses the toasts matching `predicate`, returning how many started disappearing.
+    /// Toasts already disappearing aren't counted.
+    /// ```
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// let mut t = Toasts::default();
+    /// t.error("Failed");
+    /// t.error("Failed again");
+    /// t.info("Saved");
+    /// assert_eq!(t.dismiss_level(ToastLevel::Error), 2);
+    /// assert_eq!(t.dismiss_where(|toast| toast.caption().starts_with("Failed")), 0);
+    /// assert_eq!(t.dismiss_all_toasts(), 1);
+    /// ```
+    pub fn dismiss_where(&mut self, mut predicate: impl FnMut(&Toast) -> bool) -> usize {
+        let mut count = 0;
         for toast in self.toasts.iter_mut() {
-            toast.dismiss();
+            if !toast.state.disappearing() && !toast.state.disappeared() && predicate(toast) {
+                toast.dismiss();
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Removes the oldest toast without playing the disappear animation
+    pub fn dismiss_oldest_immediate(&mut self) {
+        let oldest = (0..self.toasts.len()).min_by_key(|&i| self.toasts[i].add_index);
+        if let Some(index) = oldest {
+            let toast = self.toasts.remove(index);
+            self.keep_dismissed(Some(toast));
+        }
+    }
+
+    /// Removes the most recent toast without playing the disappear animation
+    pub fn dismiss_latest_immediate(&mut self) {
+        let latest = (0..self.toasts.len()).max_by_key(|&i| self.toasts[i].add_index);
+        if let Some(index) = latest {
+            let toast = self.toasts.remove(index);
+            self.keep_dismissed(Some(toast));
+        }
+    }
+
+    /// Removes all toasts without playing the disappear animation
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default();
+    /// t.info("First");
+    /// t.info("Second");
+    /// t.dismiss_all_toasts();
+    /// assert_eq!(t.len(), 2);
+    /// t.dismiss_all_immediate();
+    /// assert!(t.is_empty());
+    /// ```
+    pub fn dismiss_all_immediate(&mut self) {
+        let toasts = std::mem::take(&mut self.toasts);
+        self.keep_dismissed(toasts);
+    }
+
+    /// Removes every toast, including the banner, held back and channel toasts, without
+    /// playing any animation. Meant for shutdown, so disappearing toasts don't keep
+    /// requesting repaints while the app closes.
+    /// ```
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut t = Toasts::default();
+    /// t.info("Saved").set_duration(None);
+    /// t.loading("Uploading");
+    /// t.banner(ToastLevel::Warning, "Offline");
+    /// t.channel("chat").info("New message");
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| t.show(ctx));
+    /// }
+    /// t.dismiss_all_toasts();
+    ///
+    /// t.finalize();
+    /// // egui follows up a requested repaint with one more frame of its own
+    /// let _ = ctx.run(Default::default(), |ctx| t.show(ctx));
+    /// let output = ctx.run(Default::default(), |ctx| t.show(ctx));
+    /// assert!(output.shapes.is_empty());
+    /// assert_eq!(output.repaint_after, std::time::Duration::MAX);
+    /// ```
+    pub fn finalize(&mut self) {
+        self.dismiss_all_immediate();
+        let banner = self.banner.take();
+        let muted = std::mem::take(&mut self.muted_toasts);
+        self.keep_dismissed(banner.into_iter().chain(muted));
+        self.last_time = None;
+        for (_, channel) in &mut self.channels {
+            channel.finalize();
+        }
+    }
+
+    /// Takes the toasts out of the stack, e.g. to put them back with
+    /// [`Toasts::restore_snapshot`] once a modal replacing them closes. The toasts are moved
+    /// rather than copied, so nothing is lost: channels, attached data and callbacks come
+    /// back with them, and their durations don't count down meanwhile. Disappearing toasts,
+    /// and those that finished disappearing but weren't removed by [`Toasts::show`] yet, are
+    /// left behind.
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use egui_notify::{ToastLevel, ToastUpdate, Toasts};
+    ///
+    /// let disappeared = Arc::new(AtomicUsize::new(0));
+    /// let counter = disappeared.clone();
+    /// let mut t = Toasts::default();
+    /// t.info("Saved").set_data(7_u32).on_disappeared(move |_| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    /// t.warning("Low battery").set_duration(None);
+    /// let sender = t.info("Uploading").create_channel();
+    /// let _ = egui::Context::default().run(Default::default(), |ctx| t.show(ctx));
+    /// let remaining = t.iter().next().unwrap().remaining();
+    ///
+    /// let snapshot = t.take_snapshot();
+    /// assert_eq!(snapshot.len(), 3);
+    /// assert!(t.is_empty());
+    /// t.error("Modal failed");
+    ///
+    /// t.restore_snapshot(snapshot);
+    /// let restored: Vec<_> = t.iter().map(|toast| (toast.caption(), toast.level())).collect();
+    /// assert_eq!(
+    ///     restored,
+    ///     [
+    ///         ("Saved", ToastLevel::Info),
+    ///         ("Low battery", ToastLevel::Warning),
+    ///         ("Uploading", ToastLevel::Info),
+    ///     ],
+    /// );
+    /// let saved = t.iter().next().unwrap();
+    /// assert_eq!(saved.remaining(), remaining);
+    /// assert_eq!(saved.data::<u32>(), Some(&7));
+    /// assert_eq!(disappeared.load(Ordering::Relaxed), 0);
+    ///
+    /// sender.send(ToastUpdate::caption("Uploaded")).unwrap();
+    /// let _ = egui::Context::default().run(Default::default(), |ctx| t.show(ctx));
+    /// assert_eq!(t.iter().nth(2).unwrap().caption(), "Uploaded");
+    /// ```
+    ///
+    /// ```
+    /// use egui_notify::{ToastPhase, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut t = Toasts::default();
+    /// t.info("Closing").set_duration(None);
+    /// t.info("Staying").set_duration(None);
+    /// t.iter_mut().next().unwrap().dismiss();
+    /// let mut time = 0.;
+    /// while t.iter().next().unwrap().phase() != ToastPhase::Removed {
+    ///     time += 0.1;
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    /// }
+    ///
+    /// let snapshot = t.take_snapshot();
+    /// assert_eq!(snapshot.len(), 1);
+    /// assert_eq!(t.len(), 1);
+    /// ```
+    pub fn take_snapshot(&mut self) -> ToastsSnapshot {
+        let (disappearing, toasts) = std::mem::take(&mut self.toasts)
+            .into_iter()
+            .partition(|t| t.state.disappearing() || t.state.disappeared());
+        self.toasts = disappearing;
+        ToastsSnapshot { toasts }
+    }
+
+    /// Replaces the toasts in the stack with the ones of `snapshot`, see
+    /// [`Toasts::take_snapshot`]. The replaced toasts are removed without animating.
+    pub fn restore_snapshot(&mut self, snapshot: ToastsSnapshot) {
+        self.dismiss_all_immediate();
+        self.toasts = snapshot.toasts;
+    }
+
+    /// Returns the toasts removed since the last call, in the order they were removed.
+    /// Toasts removed during the same [`Toasts::show`] call are ordered by when they were added.
+    /// Toasts are only kept when enabled with [`Toasts::with_collect_dismissed`].
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default().with_collect_dismissed(true);
+    /// t.info("Uploaded").set_data(7u32);
+    /// t.dismiss_all_immediate();
+    ///
+    /// let dismissed = t.drain_dismissed();
+    /// assert_eq!(dismissed.len(), 1);
+    /// assert_eq!(dismissed[0].data::<u32>(), Some(&7));
+    /// assert!(t.drain_dismissed().is_empty());
+    /// ```
+    pub fn drain_dismissed(&mut self) -> Vec<Toast> {
+        std::mem::take(&mut self.dismissed)
+    }
+
+    fn keep_dismissed(&mut self, toasts: impl IntoIterator<Item = Toast>) {
+        for mut toast in toasts {
+            toast.finish_disappearing();
+            if self.collect_dismissed {
+                self.dismissed.push(toast);
+            }
         }
     }
 
@@ -118,6 +901,54 @@ impl Toasts {
         Toast::basic(caption).with_options(&self.default_options)
     }
 
+    /// Named collection of toasts with its own anchor and configuration, shown along with
+    /// this one. It's created with the default configuration on first use, muted if this
+    /// collection is, see [`Toasts::with_channel`] to configure it up front.
+    ///
+    /// Toasts in a channel aren't part of [`Toasts::iter`] or the dismissal methods of this
+    /// collection, and [`Toasts::show_and_interact`] returns no responses for them.
+    /// ```
+    /// use egui::Align2;
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default()
+    ///     .with_anchor(Align2::RIGHT_BOTTOM)
+    ///     .with_channel("chat", Toasts::default().with_anchor(Align2::LEFT_TOP));
+    /// t.info("Build finished");
+    /// t.channel("chat").info("New message");
+    /// assert_eq!(t.len(), 1);
+    ///
+    /// let input = egui::RawInput {
+    ///     screen_rect: Some(egui::Rect::from_min_size(
+    ///         Default::default(),
+    ///         egui::vec2(800., 600.),
+    ///     )),
+    ///     ..Default::default()
+    /// };
+    /// let output = egui::Context::default().run(input, |ctx| t.show(ctx));
+    /// let rects: Vec<_> = output.shapes.iter().filter_map(|s| match &s.shape {
+    ///     egui::Shape::Rect(rect) => Some(rect.rect),
+    ///     _ => None,
+    /// }).collect();
+    /// assert!(rects.iter().any(|r| r.max.x < 400. && r.max.y < 300.));
+    /// assert!(rects.iter().any(|r| r.min.x > 400. && r.min.y > 300.));
+    /// ```
+    pub fn channel(&mut self, name: &str) -> &mut Toasts {
+        let index = match self.channels.iter().position(|(n, _)| n == name) {
+            Some(index) => index,
+            None => {
+                let channel = Toasts {
+                    id: self.id.with(name),
+                    muted: self.muted,
+                    ..Toasts::new()
+                };
+                self.channels.push((name.to_owned(), channel));
+                self.channels.len() - 1
+            }
+        };
+        &mut self.channels[index].1
+    }
+
     /// Shortcut for adding a toast with info `success`.
     pub fn success(&mut self, caption: impl Into<String>) -> &mut Toast {
         self.add(self.base_toast(caption).success())
@@ -128,6 +959,24 @@ impl Toasts {
         self.add(self.base_toast(caption).info())
     }
 
+    /// Shortcut for adding a toast with debug `level`.
+    /// ```
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// let mut t = Toasts::default();
+    /// assert_eq!(t.debug("Frame took 20ms").level(), ToastLevel::Debug);
+    /// assert_eq!(t.trace("Entered update").level(), ToastLevel::Trace);
+    /// assert_ne!(ToastLevel::Debug.color(), ToastLevel::Trace.color());
+    /// ```
+    pub fn debug(&mut self, caption: impl Into<String>) -> &mut Toast {
+        self.add(self.base_toast(caption).debug())
+    }
+
+    /// Shortcut for adding a toast with trace `level`.
+    pub fn trace(&mut self, caption: impl Into<String>) -> &mut Toast {
+        self.add(self.base_toast(caption).trace())
+    }
+
     /// Shortcut for adding a toast with warning `level`.
     pub fn warning(&mut self, caption: impl Into<String>) -> &mut Toast {
         self.add(self.base_toast(caption).warning())
@@ -143,13 +992,1111 @@ impl Toasts {
         self.add(self.base_toast(caption))
     }
 
-    /// Should toasts be added in reverse order?
+    /// Shows a success toast with `ok_caption` on `Ok`, or an error toast with the error on `Err`.
+    /// Returns the value of `Ok`.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default();
+    /// assert_eq!(t.report("42".parse::<u32>(), "Parsed"), Some(42));
+    /// assert_eq!(t.report("nope".parse::<u32>(), "Parsed"), None);
+    /// assert_eq!(t.len(), 2);
+    /// ```
+    pub fn report<T, E: Display>(
+        &mut self,
+        result: Result<T, E>,
+        ok_caption: impl Into<String>,
+    ) -> Option<T> {
+        self.report_with(result, |_| ok_caption.into(), |e| e.to_string())
+    }
+
+    /// Like [`Toasts::report`], with captions built from the value or error.
+    pub fn report_with<T, E, C: Into<String>>(
+        &mut self,
+        result: Result<T, E>,
+        ok_caption: impl FnOnce(&T) -> C,
+        err_caption: impl FnOnce(&E) -> C,
+    ) -> Option<T> {
+        match result {
+            Ok(value) => {
+                self.success(ok_caption(&value));
+                Some(value)
+            }
+            Err(err) => {
+                self.error(err_caption(&err));
+                None
+            }
+        }
+    }
+
+    /// Shows a full-width banner at the top of the screen that stays until it is closed,
+    /// replacing any previous banner.
+    /// ```
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// let mut t = Toasts::default();
+    /// t.banner(ToastLevel::Error, "Connection lost");
+    /// t.banner(ToastLevel::Error, "Connection lost again");
+    /// assert_eq!(t.banner_caption(), Some("Connection lost again"));
+    /// ```
+    pub fn banner(&mut self, level: ToastLevel, caption: impl Into<String>) -> &mut Toast {
+        let mut banner = Toast::basic(caption);
+        banner
+            .set_level(level)
+            .set_closable(true)
+            .set_duration(None);
+        self.banner.insert(banner)
+    }
+
+    /// Caption of the current banner, if any.
+    pub fn banner_caption(&self) -> Option<&str> {
+        self.banner.as_ref().map(|b| b.caption.as_str())
+    }
+
+    /// Dismisses the banner
+    pub fn dismiss_banner(&mut self) {
+        if let Some(banner) = self.banner.as_mut() {
+            banner.dismiss();
+        }
+    }
+
+    /// Shortcut for adding a toast that shows indeterminate progress until it is finished.
+    /// ```
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// # egui_notify::__run_test_ctx(|ctx| {
+    /// let mut t = Toasts::default();
+    /// let loading = t.loading("Downloading...");
+    /// t.show(ctx);
+    /// let toast = t.iter().next().unwrap();
+    /// assert_eq!((toast.caption(), toast.level()), ("Downloading...", ToastLevel::Info));
+    /// assert_eq!(toast.remaining(), None);
+    /// // Once the work is done
+    /// loading.finish_success("Downloaded!");
+    /// t.show(ctx);
+    /// t.show(ctx);
+    /// let toast = t.iter().next().unwrap();
+    /// assert_eq!((toast.caption(), toast.level()), ("Downloaded!", ToastLevel::Success));
+    /// assert!(toast.remaining().is_some());
+    /// # });
+    /// ```
+    pub fn loading(&mut self, caption: impl Into<String>) -> LoadingToast {
+        let options = self.default_options.clone();
+        let toast = self.add(self.base_toast(caption).info());
+        toast.loading = true;
+        LoadingToast::new(toast.create_channel(), options)
+    }
+
+    /// Region of a toast hovered during the last [`Toasts::show`] call, if any.
+    ///
+    /// Hovering [`ToastRegion::Cross`] and clicking closes the toast. Like a button, the
+    /// primary button has to be both pressed and released over the cross.
+    /// ```
+    /// use egui::{Event, PointerButton, Pos2};
+    /// use egui_notify::{ToastRegion, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Hello");
+    /// let mut frame = |t: &mut Toasts, event: Event| {
+    ///     let input = egui::RawInput { events: vec![event], ..Default::default() };
+    ///     let mut rect = egui::Rect::NOTHING;
+    ///     let _ = ctx.run(input, |ctx| rect = t.show_and_interact(ctx)[0].1.rect);
+    ///     rect
+    /// };
+    /// let button = |pos, pressed| Event::PointerButton {
+    ///     pos,
+    ///     button: PointerButton::Primary,
+    ///     pressed,
+    ///     modifiers: Default::default(),
+    /// };
+    ///
+    /// let rect = frame(&mut t, Event::PointerGone);
+    /// let cross = rect.right_center() - egui::vec2(20., 0.);
+    /// frame(&mut t, Event::PointerMoved(cross));
+    /// assert_eq!(t.hovered_region(), Some(ToastRegion::Cross));
+    ///
+    /// // Pressing on the cross then releasing elsewhere keeps the toast
+    /// frame(&mut t, button(cross, true));
+    /// frame(&mut t, Event::PointerMoved(Pos2::ZERO));
+    /// frame(&mut t, button(Pos2::ZERO, false));
+    /// assert_eq!(t.debug_stats().disappearing, 0);
+    ///
+    /// frame(&mut t, Event::PointerMoved(cross));
+    /// frame(&mut t, button(cross, true));
+    /// frame(&mut t, button(cross, false));
+    /// frame(&mut t, Event::PointerGone);
+    /// assert_eq!(t.debug_stats().disappearing, 1);
+    /// ```
+    ///
+    /// Each press only counts for the cross it started on, so the crosses of different toasts
+    /// can be clicked in quick succession without waiting in between.
+    /// ```
+    /// use egui::{Event, PointerButton};
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("First");
+    /// t.info("Second");
+    /// let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let mut rects = vec![];
+    ///     let _ = ctx.run(input, |ctx| rects = t.show_and_interact(ctx));
+    ///     rects
+    ///         .into_iter()
+    ///         .map(|(_, r)| r.rect.right_center() - egui::vec2(20., 0.))
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// let click = |pos| {
+    ///     [true, false].map(|pressed| Event::PointerButton {
+    ///         pos,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Default::default(),
+    ///     })
+    /// };
+    ///
+    /// let crosses = frame(&mut t, vec![]);
+    /// let [press, release] = click(crosses[0]);
+    /// frame(&mut t, vec![Event::PointerMoved(crosses[0]), press, release]);
+    /// let [press, release] = click(crosses[1]);
+    /// frame(&mut t, vec![Event::PointerMoved(crosses[1]), press, release]);
+    /// frame(&mut t, vec![Event::PointerGone]);
+    /// assert_eq!(t.debug_stats().disappearing, 2);
+    /// ```
+    ///
+    /// The cursor turns into a pointing hand over the cross, action buttons and the body of
+    /// toasts with a [click callback](Toast::on_click).
+    /// ```
+    /// use egui::{CursorIcon, Event};
+    /// use egui_notify::{ToastRegion, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Plain");
+    /// t.info("Clickable").on_click(|| {});
+    /// let mut frame = |t: &mut Toasts, event: Event| {
+    ///     let input = egui::RawInput { events: vec![event], ..Default::default() };
+    ///     let mut rects = vec![];
+    ///     let output = ctx.run(input, |ctx| rects = t.show_and_interact(ctx));
+    ///     (rects, output.platform_output.cursor_icon, t.hovered_region())
+    /// };
+    ///
+    /// let (rects, ..) = frame(&mut t, Event::PointerGone);
+    /// let (plain, clickable) = (rects[0].1.rect, rects[1].1.rect);
+    /// let cross = plain.right_center() - egui::vec2(20., 0.);
+    /// let (_, cursor, region) = frame(&mut t, Event::PointerMoved(cross));
+    /// assert_eq!((cursor, region), (CursorIcon::PointingHand, Some(ToastRegion::Cross)));
+    /// let (_, cursor, region) = frame(&mut t, Event::PointerMoved(plain.left_center()));
+    /// assert_eq!((cursor, region), (CursorIcon::Default, Some(ToastRegion::Body)));
+    /// let (_, cursor, region) = frame(&mut t, Event::PointerMoved(clickable.left_center()));
+    /// assert_eq!((cursor, region), (CursorIcon::PointingHand, Some(ToastRegion::Body)));
+    /// let (_, cursor, region) = frame(&mut t, Event::PointerGone);
+    /// assert_eq!((cursor, region), (CursorIcon::Default, None));
+    /// ```
+    pub const fn hovered_region(&self) -> Option<ToastRegion> {
+        self.hovered_region
+    }
+
+    /// Action buttons clicked during the last [`Toasts::show`] call, as the
+    /// [timestamp](Toast::timestamp) of their toast and the id of the action.
+    /// Like the closing cross, a button is clicked by pressing and releasing over it.
+    /// ```
+    /// use egui::{Event, PointerButton, Pos2};
+    /// use egui_notify::{ToastRegion, Toasts};
+    ///
+    /// const UNDO: usize = 1;
+    /// const RETRY: usize = 2;
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// let timestamp = t.error("Upload failed").add_action("Retry", RETRY).timestamp();
+    /// t.info("Deleted").add_action("Undo", UNDO);
+    ///
+    /// let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let mut rect = egui::Rect::NOTHING;
+    ///     let output = ctx.run(input, |ctx| rect = t.show_and_interact(ctx)[0].1.rect);
+    ///     let text_rect = |text: &str| {
+    ///         output.shapes.iter().find_map(|s| match &s.shape {
+    ///             egui::Shape::Text(t) if t.galley.text() == text => {
+    ///                 Some(t.galley.rect.translate(t.pos.to_vec2()))
+    ///             }
+    ///             _ => None,
+    ///         })
+    ///     };
+    ///     (rect, text_rect("Upload failed"), text_rect("Retry"))
+    /// };
+    /// let (toast, Some(caption), Some(retry)) = frame(&mut t, vec![]) else { panic!() };
+    /// assert!(toast.contains_rect(retry));
+    /// assert!(caption.max.x < retry.min.x);
+    ///
+    /// let button = |pressed| Event::PointerButton {
+    ///     pos: retry.center(),
+    ///     button: PointerButton::Primary,
+    ///     pressed,
+    ///     modifiers: Default::default(),
+    /// };
+    /// frame(&mut t, vec![Event::PointerMoved(retry.center())]);
+    /// assert_eq!(t.hovered_region(), Some(ToastRegion::Action(RETRY)));
+    /// frame(&mut t, vec![button(true)]);
+    /// assert!(t.clicked_actions().is_empty());
+    /// frame(&mut t, vec![button(false)]);
+    /// assert_eq!(t.clicked_actions(), [(timestamp, RETRY)]);
+    /// frame(&mut t, vec![]);
+    /// assert!(t.clicked_actions().is_empty());
+    /// assert_eq!(t.debug_stats().disappearing, 0);
+    /// ```
+    pub fn clicked_actions(&self) -> &[(u128, usize)] {
+        &self.clicked_actions
+    }
+
+    /// Counts gathered during the last [`Toasts::show`] call, useful for profiling.
+    /// They only cover this collection, the counts of a [channel](Toasts::channel) are
+    /// gathered by the channel itself.
+    /// ```
+    /// use egui_notify::{ToastStats, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true).with_max_visible(2);
+    /// t.info("One");
+    /// t.info("Two");
+    /// t.info("Three");
+    /// let mut frame = |t: &mut Toasts, time: f64| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    ///     let ToastStats { total, visible, disappearing, queued, .. } = t.debug_stats();
+    ///     (total, visible, disappearing, queued)
+    /// };
+    ///
+    /// assert_eq!(frame(&mut t, 0.), (3, 2, 0, 1));
+    /// t.dismiss_oldest_toast();
+    /// assert_eq!(frame(&mut t, 0.1), (3, 2, 1, 1));
+    /// frame(&mut t, 0.2);
+    /// frame(&mut t, 0.3);
+    /// assert_eq!(frame(&mut t, 0.4), (2, 2, 0, 0));
+    /// ```
+    pub const fn debug_stats(&self) -> ToastStats {
+        self.stats
+    }
+
+    /// Where the parts of each toast were painted during the last [`Toasts::show`] call,
+    /// in paint order, for asserting on the layout in tests.
+    /// ```
+    /// # #[cfg(feature = "testing")] {
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default();
+    /// t.info("Hello, World!");
+    /// let _ = egui::Context::default().run(Default::default(), |ctx| t.show(ctx));
+    ///
+    /// let painted = &t.painted()[0];
+    /// assert!((painted.caption.width() - painted.caption_galley.rect.width()).abs() < 1e-3);
+    /// assert!(painted.rect.contains_rect(painted.caption));
+    /// assert!(painted.icon.unwrap().max.x <= painted.caption.min.x);
+    /// # }
+    /// ```
+    #[cfg(any(test, feature = "testing"))]
+    pub fn painted(&self) -> &[PaintedLayout] {
+        &self.painted
+    }
+
+    /// Margin between the toasts and the edges of the screen.
+    /// ```
+    /// use egui::vec2;
+    /// use egui_notify::Toasts;
+    ///
+    /// let t = Toasts::default()
+    ///     .with_margin(vec2(4., 6.))
+    ///     .with_spacing(2.)
+    ///     .with_padding(vec2(12., 8.))
+    ///     .reverse(true);
+    /// assert_eq!(t.margin(), vec2(4., 6.));
+    /// assert_eq!(t.spacing(), 2.);
+    /// assert_eq!(t.padding(), vec2(12., 8.));
+    /// assert_eq!(t.speed(), 4.);
+    /// assert!(t.is_reversed());
+    /// ```
+    pub const fn margin(&self) -> Vec2 {
+        self.margin
+    }
+
+    /// Spacing between stacked toasts.
+    pub const fn spacing(&self) -> f32 {
+        self.spacing
+    }
+
+    /// Padding inside toasts that don't set their own.
+    pub const fn padding(&self) -> Vec2 {
+        self.padding
+    }
+
+    /// Color used for toasts of the given level.
+    pub fn level_color(&self, level: ToastLevel) -> Color32 {
+        self.level_colors.get(level)
+    }
+
+    /// Speed of the appear animation, as the inverse of its duration in seconds.
+    pub fn speed(&self) -> f32 {
+        1. / self.appear_duration
+    }
+
+    /// How long toasts take to appear.
+    pub fn appear_duration(&self) -> Duration {
+        seconds_to_duration(self.appear_duration)
+    }
+
+    /// How long toasts take to disappear.
+    pub fn disappear_duration(&self) -> Duration {
+        seconds_to_duration(self.disappear_duration)
+    }
+
+    /// Is the newest toast nearest to the anchor? See [`Toasts::reverse`].
+    pub const fn is_reversed(&self) -> bool {
+        self.reverse
+    }
+
+    /// Time until the next toast finishes animating, shows up after its delay or expires,
+    /// for use with [`Context::request_repaint_after`]. Loading toasts animate continuously,
+    /// so they need a repaint right away. `None` if nothing is going to change by itself.
+    /// Toasts in [channels](Toasts::channel) are taken into account too.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// assert_eq!(t.next_wake(), None);
+    ///
+    /// t.info("Saved").set_duration(None);
+    /// t.info("Done").set_duration(Some(Duration::from_secs(3)));
+    /// egui_notify::__run_test_ctx(|ctx| t.show(ctx));
+    /// let wake = t.next_wake().unwrap();
+    /// assert!(wake > Duration::from_millis(2900) && wake < Duration::from_secs(3));
+    ///
+    /// t.warning("Appearing");
+    /// assert_eq!(t.next_wake(), Some(Duration::from_millis(250)));
+    ///
+    /// t.loading("Uploading");
+    /// assert_eq!(t.next_wake(), Some(Duration::ZERO));
+    ///
+    /// let mut t = Toasts::default();
+    /// t.channel("chat").info("New message");
+    /// assert_eq!(t.next_wake(), Some(Duration::from_millis(250)));
+    /// ```
+    ///
+    /// Toasts waiting for room under [`Toasts::with_max_visible`] don't need waking up.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default().with_max_visible(1).suppress_initial_animation(true);
+    /// t.info("Shown").set_duration(None);
+    /// egui_notify::__run_test_ctx(|ctx| t.show(ctx));
+    ///
+    /// t.info("Waiting");
+    /// egui_notify::__run_test_ctx(|ctx| t.show(ctx));
+    /// assert_eq!(t.debug_stats().queued, 1);
+    /// assert_eq!(t.next_wake(), None);
+    /// ```
+    pub fn next_wake(&self) -> Option<Duration> {
+        let counting = |toast: &Toast| match self.duration_counting {
+            DurationCounting::IdleOnly => toast.state.idling(),
+            DurationCounting::FromAppear => toast.state.idling() || toast.state.appearing(),
+        };
+        // Waiting toasts don't change until there's room, except for being removed
+        let queued = self.queued();
+        self.toasts
+            .iter()
+            .filter(|toast| {
+                !is_queued(toast, &queued)
+                    || toast.state.disappearing()
+                    || toast.state.disappeared()
+            })
+            .chain(self.banner.as_ref())
+            .filter_map(|toast| {
+                if let Some(delay) = toast.start_delay {
+                    return Some(delay);
+                }
+                let animation = match toast.state {
+                    ToastState::Disappeared => Some(0.),
+                    ToastState::Disappear => Some(toast.value * self.disappear_duration),
+                    _ if toast.loading => Some(0.),
+                    ToastState::Appear => Some((1. - toast.value) * self.appear_duration),
+                    ToastState::Idle => None,
+                };
+                let expiry = toast
+                    .options
+                    .duration
+                    .filter(|_| counting(toast) && !toast.toast_hovered)
+                    .map(|(_, current)| current);
+                let dismissal = toast.dismiss_delay.filter(|_| !toast.state.disappearing());
+                [animation, expiry, dismissal]
+                    .into_iter()
+                    .flatten()
+                    .reduce(f32::min)
+            })
+            .reduce(f32::min)
+            .map(seconds_to_duration)
+            .into_iter()
+            .chain(self.channels.iter().filter_map(|(_, c)| c.next_wake()))
+            .min()
+    }
+
+    /// Add indices of the stacked toasts waiting for room under the visible cap, the ones
+    /// nearest to the anchor being shown first. Toasts still waiting on their start delay
+    /// don't take up room.
+    fn queued(&self) -> Vec<usize> {
+        let Some(max_visible) = self.max_visible else {
+            return Vec::new();
+        };
+        self.toasts
+            .iter()
+            .filter(|t| t.follow.is_none() && t.start_delay.is_none())
+            .skip(max_visible)
+            .map(|t| t.add_index)
+            .collect()
+    }
+
+    /// Should the newest toast be nearest to the anchor? By default toasts stack away from
+    /// the anchor in the order they're added. This only changes where toasts are shown and
+    /// the order of [`Toasts::iter`], helpers like [`Toasts::dismiss_oldest_toast`] still go
+    /// by when toasts were added.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// for reverse in [false, true] {
+    ///     let mut t = Toasts::default().reverse(reverse);
+    ///     t.info("Old");
+    ///     t.info("New");
+    ///     let nearest = t.iter().next().unwrap().caption().to_owned();
+    ///     assert_eq!(nearest, if reverse { "New" } else { "Old" });
+    ///
+    ///     t.dismiss_oldest_immediate();
+    ///     assert_eq!(t.iter().next().unwrap().caption(), "New");
+    ///     t.info("Newer");
+    ///     t.dismiss_latest_toast();
+    ///     let dismissed = t
+    ///         .iter()
+    ///         .find(|toast| toast.phase() != egui_notify::ToastPhase::Appearing);
+    ///     assert_eq!(dismissed.unwrap().caption(), "Newer");
+    /// }
+    /// ```
+    /// Toasts already shown slide out of the way of a toast inserted in front of them:
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().reverse(true);
+    /// let mut first_y = |t: &mut Toasts, time: f64| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| t.show(ctx));
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if t.galley.text() == "First" => Some(t.pos.y),
+    ///         _ => None,
+    ///     })
+    /// };
+    /// t.info("First");
+    /// first_y(&mut t, 0.);
+    /// let before = first_y(&mut t, 1.).unwrap();
+    ///
+    /// t.info("Second");
+    /// first_y(&mut t, 1.);
+    /// let sliding = first_y(&mut t, 1.05).unwrap();
+    /// let after = first_y(&mut t, 2.).unwrap();
+    /// assert!(after < sliding && sliding < before, "{before} {sliding} {after}");
+    /// ```
     pub const fn reverse(mut self, reverse: bool) -> Self {
         self.reverse = reverse;
         self
     }
 
-    /// Where toasts should appear.
+    /// Layer order toasts are painted in, [`Order::Foreground`] by default. Toasts are painted
+    /// above the windows and areas of the same order, and below those of higher orders, which
+    /// also take the pointer: toasts can't be hovered or closed where such a layer covers them.
+    /// ```
+    /// use egui::{Event, Order, PointerButton};
+    /// use egui_notify::Toasts;
+    ///
+    /// for (order, covered) in [(Order::Middle, false), (Order::Background, true)] {
+    ///     let ctx = egui::Context::default();
+    ///     let mut t = Toasts::default().with_order(order).suppress_initial_animation(true);
+    ///     t.info("Hello");
+    ///     let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///         let input = egui::RawInput { events, ..Default::default() };
+    ///         let mut rect = egui::Rect::NOTHING;
+    ///         let _ = ctx.run(input, |ctx| {
+    ///             // A window-like area covering the whole screen
+    ///             egui::Area::new("overlay").fixed_pos(egui::Pos2::ZERO).show(ctx, |ui| {
+    ///                 ui.allocate_space(ui.ctx().screen_rect().size());
+    ///             });
+    ///             rect = t.show_and_interact(ctx)[0].1.rect;
+    ///         });
+    ///         rect
+    ///     };
+    ///
+    ///     let cross = frame(&mut t, vec![]).right_center() - egui::vec2(20., 0.);
+    ///     let click = [true, false].map(|pressed| Event::PointerButton {
+    ///         pos: cross,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Default::default(),
+    ///     });
+    ///     frame(&mut t, [vec![Event::PointerMoved(cross)], click.to_vec()].concat());
+    ///     frame(&mut t, vec![Event::PointerGone]);
+    ///     assert_eq!(t.debug_stats().disappearing, usize::from(!covered));
+    /// }
+    /// ```
+    pub const fn with_order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Should toasts be painted below modals and other foreground layers?
+    /// When enabled toasts are painted in [`Order::Middle`] instead of [`Order::Foreground`],
+    /// see [`Toasts::with_order`].
+    /// ```
+    /// use egui::{Event, Order, PointerButton};
+    /// use egui_notify::Toasts;
+    ///
+    /// for behind_modals in [false, true] {
+    ///     let ctx = egui::Context::default();
+    ///     let mut t = Toasts::default()
+    ///         .behind_modals(behind_modals)
+    ///         .suppress_initial_animation(true);
+    ///     t.info("Hello");
+    ///     let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///         let input = egui::RawInput { events, ..Default::default() };
+    ///         let mut rect = egui::Rect::NOTHING;
+    ///         let _ = ctx.run(input, |ctx| {
+    ///             // A modal-like area covering the whole screen
+    ///             egui::Area::new("modal")
+    ///                 .order(Order::Foreground)
+    ///                 .fixed_pos(egui::Pos2::ZERO)
+    ///                 .show(ctx, |ui| ui.allocate_space(ui.ctx().screen_rect().size()));
+    ///             rect = t.show_and_interact(ctx)[0].1.rect;
+    ///         });
+    ///         rect
+    ///     };
+    ///
+    ///     let cross = frame(&mut t, vec![]).right_center() - egui::vec2(20., 0.);
+    ///     let click = [true, false].map(|pressed| Event::PointerButton {
+    ///         pos: cross,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Default::default(),
+    ///     });
+    ///     frame(&mut t, [vec![Event::PointerMoved(cross)], click.to_vec()].concat());
+    ///     frame(&mut t, vec![Event::PointerGone]);
+    ///     assert_eq!(t.debug_stats().disappearing, usize::from(!behind_modals));
+    /// }
+    /// ```
+    pub const fn behind_modals(mut self, behind_modals: bool) -> Self {
+        self.order = if behind_modals {
+            Order::Middle
+        } else {
+            Order::Foreground
+        };
+        self
+    }
+
+    /// Should toasts added before the first [`Toasts::show`] appear without animating in?
+    /// ```
+    /// use egui_notify::{ToastPhase, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Restored");
+    /// let _ = ctx.run(Default::default(), |ctx| t.show(ctx));
+    /// assert_eq!(t.iter().next().unwrap().phase(), ToastPhase::Idle);
+    ///
+    /// t.info("New");
+    /// let _ = ctx.run(Default::default(), |ctx| t.show(ctx));
+    /// let phases: Vec<_> = t.iter().map(|toast| toast.phase()).collect();
+    /// assert_eq!(phases, [ToastPhase::Idle, ToastPhase::Appearing]);
+    /// ```
+    pub const fn suppress_initial_animation(mut self, suppress: bool) -> Self {
+        self.suppress_initial_animation = suppress;
+        self
+    }
+
+    /// Should adding a toast identical in caption and level to the latest one be ignored?
+    /// Only the latest toast is compared, so an interleaved different toast allows it again.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default().with_suppress_consecutive_duplicates(true);
+    /// t.info("Saved");
+    /// t.info("Saved");
+    /// assert_eq!(t.len(), 1);
+    ///
+    /// t.warning("Disk almost full");
+    /// t.info("Saved");
+    /// assert_eq!(t.len(), 3);
+    /// ```
+    pub const fn with_suppress_consecutive_duplicates(mut self, suppress: bool) -> Self {
+        self.suppress_consecutive_duplicates = suppress;
+        self
+    }
+
+    /// Maximum number of toasts, shown or waiting to be shown. Adding a toast beyond it
+    /// dismisses the oldest ones. Unlike a visible cap, evicted toasts are gone for good.
+    /// ```
+    /// use egui_notify::{ToastPhase, Toasts};
+    ///
+    /// let mut t = Toasts::default().with_max_total(3);
+    /// t.info("One");
+    /// t.info("Two");
+    /// t.info("Three");
+    /// assert!(t.iter().all(|toast| toast.phase() == ToastPhase::Appearing));
+    ///
+    /// t.info("Four");
+    /// let phases: Vec<_> = t.iter().map(|toast| (toast.caption(), toast.phase())).collect();
+    /// assert_eq!(
+    ///     phases,
+    ///     [
+    ///         ("One", ToastPhase::Disappearing),
+    ///         ("Two", ToastPhase::Appearing),
+    ///         ("Three", ToastPhase::Appearing),
+    ///         ("Four", ToastPhase::Appearing),
+    ///     ],
+    /// );
+    /// ```
+    pub const fn with_max_total(mut self, max_total: usize) -> Self {
+        self.max_total = Some(max_total);
+        self
+    }
+
+    /// Maximum number of toasts shown at once. The toasts nearest to the anchor are shown,
+    /// so the oldest ones, or the newest ones with [`Toasts::reverse`]. The others wait in
+    /// the collection until a shown toast is fully gone, which frees up its slot.
+    /// Waiting toasts don't count down their duration, and are still updated through their
+    /// channels. Following toasts aren't part of the stack and don't count, nor do toasts
+    /// still waiting on their [start delay](Toast::with_delay).
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().with_max_visible(2);
+    /// for i in 0..5 {
+    ///     t.error(format!("Error {i}"));
+    /// }
+    /// let mut frame = |t: &mut Toasts| {
+    ///     let input = egui::RawInput { predicted_dt: 0.1, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    ///     t.debug_stats()
+    /// };
+    /// for _ in 0..10 {
+    ///     frame(&mut t);
+    /// }
+    /// let stats = frame(&mut t);
+    /// assert_eq!((stats.visible, stats.queued), (2, 3));
+    /// let waiting = t.iter().find(|toast| toast.caption() == "Error 4").unwrap();
+    /// assert_eq!(waiting.remaining(), Some(std::time::Duration::from_secs_f32(3.5)));
+    ///
+    /// // The oldest toast is a shown one, dismissing it lets the next one in
+    /// t.dismiss_oldest_toast();
+    /// while t.len() == 5 {
+    ///     frame(&mut t);
+    /// }
+    /// let stats = frame(&mut t);
+    /// assert_eq!((stats.visible, stats.queued), (2, 2));
+    /// ```
+    /// When reversed the newest toasts are shown, and delayed toasts make room until they
+    /// appear:
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::{Toast, Toasts};
+    ///
+    /// let painted = |t: &mut Toasts, ctx: &egui::Context, time: f64| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| t.show(ctx));
+    ///     output.shapes.iter().filter_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if ["Old", "New", "Delayed"].contains(&t.galley.text()) => {
+    ///             Some(t.galley.text().to_owned())
+    ///         }
+    ///         _ => None,
+    ///     }).collect::<Vec<_>>()
+    /// };
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().with_max_visible(1).reverse(true);
+    /// t.info("Old");
+    /// t.info("New");
+    /// assert_eq!(painted(&mut t, &ctx, 0.), ["New"]);
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().with_max_visible(1);
+    /// t.add(Toast::basic("Delayed").with_delay(Duration::from_secs(1)));
+    /// t.info("New");
+    /// assert_eq!(painted(&mut t, &ctx, 0.), ["New"]);
+    /// assert_eq!(t.debug_stats().queued, 0);
+    /// painted(&mut t, &ctx, 1.1);
+    /// assert_eq!(painted(&mut t, &ctx, 1.2), ["Delayed"]);
+    /// assert_eq!(t.debug_stats().queued, 1);
+    /// ```
+    pub const fn with_max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = Some(max_visible);
+        self
+    }
+
+    /// Called when a toast with [`ToastOptions::notify`] set is added, e.g. to play a sound.
+    /// Toasts added less than the notify interval after the last call don't call it again,
+    /// so a burst of toasts only plays one sound, see [`Toasts::with_notify_interval`].
+    /// ```
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    /// use std::time::Duration;
+    /// use egui_notify::{Toast, Toasts};
+    ///
+    /// let cues = Arc::new(AtomicUsize::new(0));
+    /// let counter = cues.clone();
+    /// let mut t = Toasts::default()
+    ///     .with_notify_interval(Duration::from_secs(60))
+    ///     .with_notify_hook(move |_| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     });
+    ///
+    /// t.info("Quiet");
+    /// assert_eq!(cues.load(Ordering::Relaxed), 0);
+    /// for _ in 0..3 {
+    ///     t.add(Toast::basic("Disk full").error().with_notify(true));
+    /// }
+    /// assert_eq!(cues.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn with_notify_hook(mut self, hook: impl FnMut(&Toast) + Send + 'static) -> Self {
+        self.notify_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Minimum time between two calls of the notify hook, one second by default. Measured
+    /// in input time of the frames the toasts are shown in, like the toast durations.
+    /// ```
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    /// use std::time::Duration;
+    /// use egui_notify::{Toast, Toasts};
+    ///
+    /// let cues = Arc::new(AtomicUsize::new(0));
+    /// let counter = cues.clone();
+    /// let mut t = Toasts::default()
+    ///     .with_notify_interval(Duration::from_secs(2))
+    ///     .with_notify_hook(move |_| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     });
+    ///
+    /// let ctx = egui::Context::default();
+    /// for time in [0., 1., 3.] {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    ///     t.add(Toast::basic("Disk full").error().with_notify(true));
+    /// }
+    /// assert_eq!(cues.load(Ordering::Relaxed), 2);
+    /// ```
+    pub const fn with_notify_interval(mut self, interval: Duration) -> Self {
+        self.notify_interval = interval;
+        self
+    }
+
+    /// Speed of the appear and disappear animations, as the inverse of their duration in
+    /// seconds. `4.0` by default, so toasts take a quarter of a second to appear.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::Toasts;
+    ///
+    /// let t = Toasts::default().with_speed(2.);
+    /// assert_eq!(t.appear_duration(), Duration::from_millis(500));
+    /// assert_eq!(t.disappear_duration(), Duration::from_millis(500));
+    ///
+    /// let t = t.with_appear_duration(Duration::from_millis(100));
+    /// assert_eq!(t.speed(), 10.);
+    ///
+    /// // A speed of zero never finishes animating
+    /// let t = t.with_speed(0.);
+    /// assert_eq!(t.appear_duration(), Duration::MAX);
+    /// ```
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.appear_duration = 1. / speed;
+        self.disappear_duration = 1. / speed;
+        self
+    }
+
+    /// How long toasts take to appear, instead of setting it with [`Toasts::with_speed`].
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::{ToastPhase, Toasts};
+    ///
+    /// for appear in [0.25, 0.5] {
+    ///     let ctx = egui::Context::default();
+    ///     ctx.set_fonts(egui::FontDefinitions::empty());
+    ///     let mut t = Toasts::default().with_appear_duration(Duration::from_secs_f32(appear));
+    ///     t.info("Hello");
+    ///     let dt = 0.05;
+    ///     let mut frame = |t: &mut Toasts| {
+    ///         let input = egui::RawInput { predicted_dt: dt, ..Default::default() };
+    ///         let _ = ctx.run(input, |ctx| t.show(ctx));
+    ///     };
+    ///     while t.iter().next().unwrap().phase() == ToastPhase::Appearing {
+    ///         frame(&mut t);
+    ///     }
+    ///     let appear_time = ctx.input(|i| i.time) as f32;
+    ///     assert!((appear - 1e-3..appear + dt).contains(&appear_time));
+    /// }
+    /// ```
+    pub fn with_appear_duration(mut self, duration: Duration) -> Self {
+        self.appear_duration = duration.as_secs_f32();
+        self
+    }
+
+    /// How long toasts take to disappear, instead of setting it with [`Toasts::with_speed`].
+    pub fn with_disappear_duration(mut self, duration: Duration) -> Self {
+        self.disappear_duration = duration.as_secs_f32();
+        self
+    }
+
+    /// How toasts animate when appearing and disappearing.
+    /// ```
+    /// use egui_notify::{AnimationKind, Toast, ToastTransform, Toasts};
+    ///
+    /// fn body(t: &mut Toasts) -> egui::Rect {
+    ///     t.info("Hello");
+    ///     let output = egui::Context::default().run(Default::default(), |ctx| t.show(ctx));
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Rect(rect) => Some(rect.rect),
+    ///         _ => None,
+    ///     }).unwrap()
+    /// }
+    ///
+    /// let rest = |_: &Toast, _| ToastTransform::default();
+    /// let drop = |_: &Toast, value: f32| ToastTransform {
+    ///     offset: egui::vec2(0., -40. * (1. - value)),
+    ///     ..Default::default()
+    /// };
+    /// let at_rest = body(&mut Toasts::default().with_animation(AnimationKind::Custom(rest)));
+    /// let dropping = body(&mut Toasts::default().with_animation(AnimationKind::Custom(drop)));
+    /// assert_eq!(dropping, at_rest.translate(egui::vec2(0., -40.)));
+    /// ```
+    ///
+    /// ```
+    /// use egui_notify::{AnimationKind, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().with_animation(AnimationKind::Pop);
+    /// t.info("Hello");
+    /// let mut full = egui::Vec2::ZERO;
+    /// let output = ctx.run(Default::default(), |ctx| {
+    ///     full = t.iter().next().unwrap().measure(ctx, egui::vec2(10., 10.));
+    ///     t.show(ctx);
+    /// });
+    /// let body = output
+    ///     .shapes
+    ///     .iter()
+    ///     .find_map(|s| match &s.shape {
+    ///         egui::Shape::Rect(rect) => Some(rect.rect),
+    ///         _ => None,
+    ///     })
+    ///     .unwrap();
+    /// assert!(body.width() < full.x && body.height() < full.y);
+    /// ```
+    pub const fn with_animation(mut self, animation: AnimationKind) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// How toasts animate when disappearing, instead of reversing the appear animation.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::{AnimationKind, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default()
+    ///     .with_animation(AnimationKind::Slide)
+    ///     .with_exit_animation(AnimationKind::Fade)
+    ///     .with_disappear_duration(Duration::from_secs(10));
+    /// t.info("Hello").set_duration(None);
+    ///
+    /// let mut frame = |t: &mut Toasts, time: f64| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| t.show(ctx));
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Rect(rect) => Some((rect.rect, rect.fill.a())),
+    ///         _ => None,
+    ///     })
+    /// };
+    /// for i in 0..20 {
+    ///     frame(&mut t, i as f64 * 0.1);
+    /// }
+    /// let (rest, opaque) = frame(&mut t, 2.).unwrap();
+    /// t.dismiss_all_toasts();
+    /// for i in 20..50 {
+    ///     frame(&mut t, i as f64 * 0.1);
+    /// }
+    /// let (fading, alpha) = frame(&mut t, 5.).unwrap();
+    /// assert_eq!(fading, rest);
+    /// assert!(alpha < opaque);
+    /// ```
+    pub const fn with_exit_animation(mut self, animation: AnimationKind) -> Self {
+        self.exit_animation = Some(animation);
+        self
+    }
+
+    /// Color of a rounded panel painted behind the whole stack, `None` for no panel.
+    /// ```
+    /// use egui_notify::{Color32, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let panel = Color32::from_black_alpha(100);
+    /// let mut t = Toasts::default().with_container_background(Some(panel));
+    /// t.info("Hello");
+    /// t.info("World");
+    /// let output = ctx.run(Default::default(), |ctx| t.show(ctx));
+    /// let rects: Vec<_> = output
+    ///     .shapes
+    ///     .iter()
+    ///     .filter_map(|s| match &s.shape {
+    ///         egui::Shape::Rect(rect) => Some(rect),
+    ///         _ => None,
+    ///     })
+    ///     .collect();
+    /// assert_eq!(rects[0].fill, panel);
+    /// assert!(rects[1..].iter().all(|r| rects[0].rect.contains_rect(r.rect)));
+    /// ```
+    pub const fn with_container_background(mut self, color: Option<Color32>) -> Self {
+        self.container_background = color;
+        self
+    }
+
+    /// Title of a header row shown above the stack, with a "Clear all" button dismissing
+    /// every toast, e.g. for a notification panel. `None` for no header, the default.
+    /// The header is shown while there are toasts that aren't disappearing.
+    /// ```
+    /// use egui::{Event, PointerButton, Pos2};
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default()
+    ///     .with_header(Some("Notifications".into()))
+    ///     .suppress_initial_animation(true);
+    /// t.info("Saved");
+    /// t.warning("Low battery");
+    ///
+    /// let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| t.show(ctx));
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if t.galley.text() == "Clear all" => {
+    ///             Some(t.galley.rect.translate(t.pos.to_vec2()).center())
+    ///         }
+    ///         _ => None,
+    ///     })
+    /// };
+    /// let clear = frame(&mut t, vec![]).unwrap();
+    /// let button = |pressed| Event::PointerButton {
+    ///     pos: clear,
+    ///     button: PointerButton::Primary,
+    ///     pressed,
+    ///     modifiers: Default::default(),
+    /// };
+    /// frame(&mut t, vec![Event::PointerMoved(clear), button(true), button(false)]);
+    /// assert!(frame(&mut t, vec![Event::PointerGone]).is_none());
+    /// assert_eq!(t.debug_stats().disappearing, 2);
+    /// ```
+    pub fn with_header(mut self, header: Option<String>) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// When durations count down, see [`DurationCounting`].
+    /// ```
+    /// use egui_notify::{DurationCounting, Toasts};
+    /// use std::time::Duration;
+    ///
+    /// let lifetime = |counting| {
+    ///     let ctx = egui::Context::default();
+    ///     ctx.set_fonts(egui::FontDefinitions::empty());
+    ///     let mut t = Toasts::default().with_duration_counting(counting);
+    ///     t.info("Hello").set_duration(Some(Duration::from_millis(500)));
+    ///     let mut frames = 0;
+    ///     while !t.is_empty() {
+    ///         let input = egui::RawInput { predicted_dt: 0.05, ..Default::default() };
+    ///         let _ = ctx.run(input, |ctx| t.show(ctx));
+    ///         frames += 1;
+    ///     }
+    ///     frames
+    /// };
+    /// assert!(lifetime(DurationCounting::FromAppear) < lifetime(DurationCounting::IdleOnly));
+    /// ```
+    pub const fn with_duration_counting(mut self, duration_counting: DurationCounting) -> Self {
+        self.duration_counting = duration_counting;
+        self
+    }
+
+    /// Which toast should be on top when toasts overlap?
+    /// ```
+    /// use egui_notify::{PaintOrder, Toasts};
+    ///
+    /// let on_top = |paint_order| {
+    ///     // Newest nearest to the anchor, so the stack order paints it first
+    ///     let mut t = Toasts::default()
+    ///         .reverse(true)
+    ///         .with_spacing(-20.)
+    ///         .with_paint_order(paint_order);
+    ///     t.info("Old");
+    ///     t.info("New");
+    ///     let output = egui::Context::default().run(Default::default(), |ctx| t.show(ctx));
+    ///     output.shapes.iter().rev().find_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if ["Old", "New"].contains(&t.galley.text()) => {
+    ///             Some(t.galley.text().to_owned())
+    ///         }
+    ///         _ => None,
+    ///     }).unwrap()
+    /// };
+    /// assert_eq!(on_top(PaintOrder::Stack), "Old");
+    /// assert_eq!(on_top(PaintOrder::NewestOnTop), "New");
+    /// assert_eq!(on_top(PaintOrder::OldestOnTop), "Old");
+    /// ```
+    pub const fn with_paint_order(mut self, paint_order: PaintOrder) -> Self {
+        self.paint_order = paint_order;
+        self
+    }
+
+    /// Should removed toasts be kept until they are taken with [`Toasts::drain_dismissed`]?
+    pub const fn with_collect_dismissed(mut self, collect_dismissed: bool) -> Self {
+        self.collect_dismissed = collect_dismissed;
+        self
+    }
+
+    /// Where toasts should appear. Changing the anchor while toasts are shown slides them
+    /// over to their new place.
+    /// ```
+    /// use egui_notify::{Align2, Toasts};
+    ///
+    /// let mut t = Toasts::default().with_anchor(Align2::LEFT_TOP);
+    /// assert_eq!(t.anchor, Align2::LEFT_TOP);
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut caption_pos = |t: &mut Toasts, time: f64| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| t.show(ctx));
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if t.galley.text() == "Hello" => Some(t.pos),
+    ///         _ => None,
+    ///     }).unwrap()
+    /// };
+    /// t.info("Hello");
+    /// caption_pos(&mut t, 0.);
+    /// let left_top = caption_pos(&mut t, 1.);
+    ///
+    /// t.anchor = Align2::RIGHT_BOTTOM;
+    /// caption_pos(&mut t, 1.);
+    /// let sliding = caption_pos(&mut t, 1.05);
+    /// let right_bottom = caption_pos(&mut t, 2.);
+    /// assert!(left_top.x < sliding.x && sliding.x < right_bottom.x);
+    /// assert!(left_top.y < sliding.y && sliding.y < right_bottom.y);
+    /// assert_eq!(t.iter().next().unwrap().phase(), egui_notify::ToastPhase::Idle);
+    /// ```
     pub const fn with_anchor(mut self, anchor: Align2) -> Self {
         self.anchor = anchor;
         self
@@ -161,52 +2108,732 @@ impl Toasts {
         self
     }
 
+    /// How spacing between adjacent toasts advances along the stack.
+    /// ```
+    /// use egui_notify::{SpacingMode, Toasts};
+    ///
+    /// let stack_height = |mode| {
+    ///     let mut t = Toasts::default().suppress_initial_animation(true).with_spacing_mode(mode);
+    ///     for i in 0..10 {
+    ///         t.info(format!("Toast {i}"));
+    ///     }
+    ///     let mut rects = vec![];
+    ///     let _ = egui::Context::default().run(Default::default(), |ctx| {
+    ///         rects = t.show_and_interact(ctx).into_iter().map(|(_, r)| r.rect).collect();
+    ///     });
+    ///     assert_eq!(rects.len(), 10);
+    ///     rects.into_iter().reduce(egui::Rect::union).unwrap().height()
+    /// };
+    /// assert!(stack_height(SpacingMode::Compressed) < stack_height(SpacingMode::Uniform));
+    /// ```
+    pub const fn with_spacing_mode(mut self, spacing_mode: SpacingMode) -> Self {
+        self.spacing_mode = spacing_mode;
+        self
+    }
+
     /// Margin or distance from screen to toasts' bounding boxes
+    /// ```
+    /// use egui_notify::{vec2, Toasts};
+    ///
+    /// let t = Toasts::default().with_margin(vec2(16., 16.));
+    /// ```
     pub const fn with_margin(mut self, margin: Vec2) -> Self {
         self.margin = margin;
         self
     }
 
-    /// Padding or distance from toasts' bounding boxes to inner contents.
-    pub const fn with_padding(mut self, padding: Vec2) -> Self {
-        self.padding = padding;
+    /// Insets of the screen covered by system UI, such as notches or rounded corners on
+    /// mobile. Toasts are kept clear of them, with the margin applied on top.
+    /// ```
+    /// use egui_notify::{Margin, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut right_top = |t: &mut Toasts| {
+    ///     t.info("Hello");
+    ///     let output = ctx.run(Default::default(), |ctx| t.show(ctx));
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Rect(rect) => Some(rect.rect.right_top()),
+    ///         _ => None,
+    ///     })
+    /// };
+    ///
+    /// let plain = right_top(&mut Toasts::default().suppress_initial_animation(true)).unwrap();
+    /// let inset = Margin { right: 20., bottom: 40., ..Margin::ZERO };
+    /// let mut t = Toasts::default().suppress_initial_animation(true).with_safe_area(inset);
+    /// assert_eq!(right_top(&mut t).unwrap(), plain - egui::vec2(20., 40.));
+    /// ```
+    pub const fn with_safe_area(mut self, safe_area: Margin) -> Self {
+        self.safe_area = safe_area;
         self
     }
-}
 
-impl Toasts {
-    /// Displays toast queue
-    pub fn show(&mut self, ctx: &Context) {
-        let screen_rect = ctx.screen_rect();
-        let mut toast_anchor = self
-            .anchor
-            .pos_in_rect_with_margin(&screen_rect, self.margin);
-        let toasts_layer_id = Id::new("toasts");
-        let painter = ctx.layer_painter(LayerId::new(Order::Foreground, toasts_layer_id));
-        let mut dismiss: Option<usize> = None;
+    /// Should the progress bar ease toward the remaining time instead of tracking it exactly?
+    /// Smooths out jitter on variable frame rates. The bar eases by the input time elapsed
+    /// between frames, so it catches up when toasts are shown on sparse frames.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::Toasts;
+    ///
+    /// let elapsed_width = |smooth, frames: &[(f64, bool)]| {
+    ///     let ctx = egui::Context::default();
+    ///     let mut t = Toasts::default()
+    ///         .suppress_initial_animation(true)
+    ///         .with_smooth_progress(smooth);
+    ///     t.info("Hello").set_duration(Some(Duration::from_secs(2)));
+    ///     let mut width = 0.;
+    ///     for &(time, show) in frames {
+    ///         let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///         let output = ctx.run(input, |ctx| if show { t.show(ctx) });
+    ///         width = output.shapes.iter().find_map(|s| match &s.shape {
+    ///             egui::Shape::Rect(r) if r.stroke.width == 2. => Some(r.rect.width()),
+    ///             _ => None,
+    ///         }).unwrap_or(width);
+    ///     }
+    ///     width
+    /// };
+    /// let steady: Vec<_> = (0..5).map(|i| (i as f64 / 60., true)).collect();
+    /// let smoothed = elapsed_width(true, &steady);
+    /// assert!(0. < smoothed && smoothed < elapsed_width(false, &steady));
+    ///
+    /// let sparse: Vec<_> = (0..10).map(|i| (i as f64 * 0.1, i % 3 == 0)).collect();
+    /// assert_eq!(elapsed_width(true, &sparse), elapsed_width(false, &sparse));
+    /// ```
+    pub const fn with_smooth_progress(mut self, smooth_progress: bool) -> Self {
+        self.smooth_progress = smooth_progress;
+        self
+    }
+
+    /// Should captions be selectable so they can be copied?
+    /// Selectable captions are laid out as read-only text edits instead of being painted.
+    /// ```
+    /// use egui::{Event, PointerButton};
+    /// use egui_notify::Toasts;
+    ///
+    /// for selectable in [false, true] {
+    ///     let ctx = egui::Context::default();
+    ///     let mut t = Toasts::default()
+    ///         .suppress_initial_animation(true)
+    ///         .with_selectable_text(selectable);
+    ///     t.error("Upload failed");
+    ///     let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///         let input = egui::RawInput { events, ..Default::default() };
+    ///         let output = ctx.run(input, |ctx| t.show(ctx));
+    ///         output.shapes.iter().find_map(|s| match &s.shape {
+    ///             egui::Shape::Text(t) if t.galley.text() == "Upload failed" => {
+    ///                 Some(t.galley.rect.translate(t.pos.to_vec2()).center())
+    ///             }
+    ///             _ => None,
+    ///         })
+    ///     };
+    ///     // Areas are laid out invisibly on their first frame
+    ///     frame(&mut t, vec![]);
+    ///     let caption = frame(&mut t, vec![]).unwrap();
+    ///     let button = |pressed| Event::PointerButton {
+    ///         pos: caption,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Default::default(),
+    ///     };
+    ///     frame(&mut t, vec![Event::PointerMoved(caption)]);
+    ///     frame(&mut t, vec![button(true)]);
+    ///     frame(&mut t, vec![button(false)]);
+    ///     assert_eq!(ctx.memory(|m| m.focus().is_some()), selectable);
+    /// }
+    /// ```
+    pub const fn with_selectable_text(mut self, selectable_text: bool) -> Self {
+        self.selectable_text = selectable_text;
+        self
+    }
+
+    /// Options toasts added with the shortcut methods start from. They are replaced as a
+    /// whole, including those set with [`Toasts::with_single_line`] and
+    /// [`Toasts::with_max_width`], so call this first.
+    /// ```
+    /// use egui_notify::{ToastOptions, Toasts};
+    ///
+    /// let options = ToastOptions::default().with_closable(false);
+    /// let mut t = Toasts::default().with_default_options(options.clone());
+    /// assert!(!t.info("Hello").closable());
+    ///
+    /// let t = Toasts::default()
+    ///     .with_default_options(options.clone())
+    ///     .with_max_width(200.);
+    /// assert_eq!(t.default_options.max_width, Some(200.));
+    /// let t = Toasts::default().with_max_width(200.).with_default_options(options);
+    /// assert_eq!(t.default_options.max_width, None);
+    /// ```
+    pub fn with_default_options(mut self, default_options: ToastOptions) -> Self {
+        self.default_options = default_options;
+        self
+    }
+
+    /// Should captions of toasts added with the shortcut methods be forced onto a single line?
+    /// Overwritten by a later [`Toasts::with_default_options`].
+    pub const fn with_single_line(mut self, single_line: bool) -> Self {
+        self.default_options.single_line = single_line;
+        self
+    }
+
+    /// Should toasts be shown as bars spanning the width of the screen, less the margins?
+    /// ```
+    /// use egui_notify::{Align2, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().with_anchor(Align2::CENTER_TOP).with_full_width(true);
+    /// t.info("Update available");
+    /// let input = egui::RawInput {
+    ///     screen_rect: Some(egui::Rect::from_min_size(
+    ///         Default::default(),
+    ///         egui::vec2(800., 600.),
+    ///     )),
+    ///     ..Default::default()
+    /// };
+    /// let mut rect = egui::Rect::NOTHING;
+    /// let _ = ctx.run(input, |ctx| rect = t.show_and_interact(ctx)[0].1.rect);
+    /// assert_eq!(rect.width(), 800. - 2. * 8.);
+    /// ```
+    pub const fn with_full_width(mut self, full_width: bool) -> Self {
+        self.full_width = full_width;
+        self
+    }
+
+    /// Adds a named channel shown along with these toasts, see [`Toasts::channel`].
+    pub fn with_channel(mut self, name: impl Into<String>, mut channel: Toasts) -> Self {
+        let name = name.into();
+        channel.id = self.id.with(&name);
+        self.channels.retain(|(n, _)| *n != name);
+        self.channels.push((name, channel));
+        self
+    }
+
+    /// Width toasts added with the shortcut methods may grow to before their captions wrap.
+    /// Overwritten by a later [`Toasts::with_default_options`].
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// # let _ = egui::Context::default().run(Default::default(), |ctx| {
+    /// let padding = egui::vec2(10., 10.);
+    /// let mut t = Toasts::default().with_max_width(200.);
+    /// let short = t.info("Saved").measure(ctx, padding);
+    /// let long = t
+    ///     .info("Saved the file, but some of its metadata could not be written")
+    ///     .measure(ctx, padding);
+    /// assert!(short.x < 200.);
+    /// assert_eq!(long.x, 200.);
+    /// assert!(long.y > short.y);
+    /// # });
+    /// ```
+    pub const fn with_max_width(mut self, max_width: f32) -> Self {
+        self.default_options.max_width = Some(max_width);
+        self
+    }
+
+    /// Should hovering a toast show a tooltip with its level, age and remaining time?
+    /// ```
+    /// use egui::{Event, Order};
+    /// use egui_notify::Toasts;
+    ///
+    /// for hover_info in [false, true] {
+    ///     let ctx = egui::Context::default();
+    ///     let mut t = Toasts::default()
+    ///         .suppress_initial_animation(true)
+    ///         .with_hover_info(hover_info);
+    ///     t.info("Hello");
+    ///     let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///         let input = egui::RawInput { events, ..Default::default() };
+    ///         let mut rect = egui::Rect::NOTHING;
+    ///         let _ = ctx.run(input, |ctx| rect = t.show_and_interact(ctx)[0].1.rect);
+    ///         rect
+    ///     };
+    ///     let toast = frame(&mut t, vec![]);
+    ///     frame(&mut t, vec![Event::PointerMoved(toast.center())]);
+    ///     let tooltip = ctx.memory(|m| m.layer_ids().any(|layer| layer.order == Order::Tooltip));
+    ///     assert_eq!(tooltip, hover_info);
+    /// }
+    /// ```
+    pub const fn with_hover_info(mut self, hover_info: bool) -> Self {
+        self.hover_info = hover_info;
+        self
+    }
+
+    /// Ignore clicks on the closing cross while a toast appears and until it has been shown
+    /// for `grace`, so a toast popping up under the pointer isn't dismissed by accident.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui::{Event, PointerButton, Pos2};
+    /// use egui_notify::{AnimationKind, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default()
+    ///     .with_animation(AnimationKind::Fade)
+    ///     .with_dismiss_grace(Duration::from_millis(500));
+    /// t.info("Hello");
+    ///
+    /// let mut frame = |t: &mut Toasts, time: f64, events: Vec<Event>| {
+    ///     let input = egui::RawInput { time: Some(time), events, ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| t.show(ctx));
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if t.galley.text() == "❌" => {
+    ///             Some(t.galley.rect.translate(t.pos.to_vec2()).center())
+    ///         }
+    ///         _ => None,
+    ///     })
+    /// };
+    /// let click = |pos: Pos2| {
+    ///     let button = |pressed| Event::PointerButton {
+    ///         pos,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Default::default(),
+    ///     };
+    ///     vec![Event::PointerMoved(pos), button(true), button(false)]
+    /// };
+    ///
+    /// let cross = frame(&mut t, 0., vec![]).unwrap();
+    /// frame(&mut t, 0.1, click(cross));
+    /// frame(&mut t, 0.2, vec![]);
+    /// assert_eq!(t.debug_stats().disappearing, 0);
+    ///
+    /// for i in 3..10 {
+    ///     frame(&mut t, i as f64 * 0.1, vec![]);
+    /// }
+    /// frame(&mut t, 1., click(cross));
+    /// frame(&mut t, 1.1, vec![]);
+    /// assert_eq!(t.debug_stats().disappearing, 1);
+    /// ```
+    pub fn with_dismiss_grace(mut self, grace: Duration) -> Self {
+        self.dismiss_grace = Some(grace.as_secs_f32());
+        self
+    }
+
+    /// Should a disappearing toast keep its slot until it is fully gone?
+    /// Enabled by default, which keeps neighbours still until the toast is removed.
+    /// When disabled, the slot shrinks along with the disappear animation.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("First");
+    /// t.info("Second");
+    /// t.info("Third");
+    /// let mut third_pos = |t: &mut Toasts, time: f64| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| t.show(ctx));
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if t.galley.text() == "Third" => Some(t.pos),
+    ///         _ => None,
+    ///     }).unwrap()
+    /// };
+    /// let before = third_pos(&mut t, 0.);
+    ///
+    /// t.iter_mut().nth(1).unwrap().dismiss();
+    /// let mut time = 0.;
+    /// while t.len() == 3 {
+    ///     time += 0.05;
+    ///     assert_eq!(third_pos(&mut t, time), before);
+    /// }
+    /// third_pos(&mut t, time + 0.05);
+    /// assert_ne!(third_pos(&mut t, time + 1.), before);
+    /// ```
+    pub const fn with_reserve_space_until_removed(mut self, reserve_space: bool) -> Self {
+        self.reserve_space = reserve_space;
+        self
+    }
+
+    /// Color used for toasts of the given level, for both their icon and their border.
+    /// Toasts without a level can be given a border color too.
+    /// ```
+    /// use egui::Color32;
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// let brand = Color32::from_rgb(255, 0, 128);
+    /// let t = Toasts::default();
+    /// assert_eq!(t.level_color(ToastLevel::Error), ToastLevel::Error.color());
+    ///
+    /// for level in [ToastLevel::Error, ToastLevel::None] {
+    ///     let mut t = Toasts::default().with_level_color(level, brand);
+    ///     t.basic("Hello").set_level(level);
+    ///     assert_eq!(t.level_color(level), brand);
+    ///
+    ///     let output = egui::Context::default().run(Default::default(), |ctx| t.show(ctx));
+    ///     let border = output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Rect(rect) => Some(rect.stroke.color),
+    ///         _ => None,
+    ///     });
+    ///     let icon = output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if t.galley.text() == level.icon() => {
+    ///             Some(t.galley.job.sections[0].format.color)
+    ///         }
+    ///         _ => None,
+    ///     });
+    ///     assert_eq!(border, Some(brand));
+    ///     assert_eq!(icon, (level != ToastLevel::None).then_some(brand));
+    /// }
+    /// ```
+    pub fn with_level_color(mut self, level: ToastLevel, color: Color32) -> Self {
+        self.level_colors.set(level, color);
+        self
+    }
+
+    /// Color used for toasts of the given level, parsed from `#rrggbb` or `#rrggbbaa`.
+    /// ```
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// assert!(Toasts::default().with_level_color_hex(ToastLevel::Info, "#4080c0").is_ok());
+    /// assert!(Toasts::default().with_level_color_hex(ToastLevel::Info, "#4080c080").is_ok());
+    /// assert!(Toasts::default().with_level_color_hex(ToastLevel::Info, "4080c0").is_err());
+    /// assert!(Toasts::default().with_level_color_hex(ToastLevel::Info, "#40zzc0").is_err());
+    /// ```
+    pub fn with_level_color_hex(
+        self,
+        level: ToastLevel,
+        hex: &str,
+    ) -> Result<Self, ParseHexColorError> {
+        Ok(self.with_level_color(level, parse_hex_color(hex)?))
+    }
+
+    /// Padding or distance from toasts' bounding boxes to inner contents.
+    pub const fn with_padding(mut self, padding: Vec2) -> Self {
+        self.padding = padding;
+        self
+    }
+}
+
+impl Toasts {
+    /// Displays toast queue
+    ///
+    /// Toasts stacked past the edge of the screen are neither laid out nor painted,
+    /// but their durations keep counting down.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut t = Toasts::default();
+    /// for i in 0..5000 {
+    ///     t.info(format!("Toast {i}")).set_duration(None);
+    /// }
+    /// for _ in 0..3 {
+    ///     let input = egui::RawInput {
+    ///         screen_rect: Some(egui::Rect::from_min_size(
+    ///             Default::default(),
+    ///             egui::vec2(800., 600.),
+    ///         )),
+    ///         ..Default::default()
+    ///     };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    /// }
+    /// assert_eq!(t.debug_stats().total, 5000);
+    /// assert!((1..100).contains(&t.debug_stats().visible));
+    /// ```
+    ///
+    /// Nothing is shown, and no time passes for the toasts, while the screen is empty.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default();
+    /// t.info("Hello");
+    /// for size in [egui::Vec2::ZERO, egui::Vec2::splat(f32::NAN), egui::vec2(800., 600.)] {
+    ///     let input = egui::RawInput {
+    ///         screen_rect: Some(egui::Rect::from_min_size(Default::default(), size)),
+    ///         ..Default::default()
+    ///     };
+    ///     let output = ctx.run(input, |ctx| t.show(ctx));
+    ///     assert!(output.shapes.iter().all(|s| s.shape.visual_bounding_rect().is_finite()));
+    /// }
+    /// assert_eq!(t.debug_stats().visible, 1);
+    /// ```
+    /// This holds for countdowns too, e.g. while the window is minimized:
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Hello").set_duration(Some(Duration::from_secs(4)));
+    /// let mut frame = |t: &mut Toasts, time: f64, size: egui::Vec2| {
+    ///     let input = egui::RawInput {
+    ///         time: Some(time),
+    ///         screen_rect: Some(egui::Rect::from_min_size(Default::default(), size)),
+    ///         ..Default::default()
+    ///     };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    /// };
+    ///
+    /// frame(&mut t, 0., egui::vec2(800., 600.));
+    /// frame(&mut t, 1., egui::vec2(800., 600.));
+    /// frame(&mut t, 2., egui::Vec2::ZERO);
+    /// frame(&mut t, 100., egui::vec2(800., 600.));
+    /// let remaining = t.iter().next().unwrap().remaining().unwrap();
+    /// assert!(remaining > Duration::from_secs_f32(2.5), "{remaining:?}");
+    /// ```
+    ///
+    /// Animations follow the input time, so they take as long whatever the frame rate,
+    /// as long as frames are at most a tenth of a second apart.
+    /// ```
+    /// use egui_notify::{ToastPhase, Toasts};
+    ///
+    /// for dt in [0.1, 1. / 30., 1. / 144.] {
+    ///     let ctx = egui::Context::default();
+    ///     ctx.set_fonts(egui::FontDefinitions::empty());
+    ///     let mut t = Toasts::default();
+    ///     t.info("Hello");
+    ///     while t.iter().next().unwrap().phase() == ToastPhase::Appearing {
+    ///         let input = egui::RawInput { predicted_dt: dt, ..Default::default() };
+    ///         let _ = ctx.run(input, |ctx| t.show(ctx));
+    ///     }
+    ///     let appear_time = ctx.input(|i| i.time) as f32;
+    ///     assert!((0.25..0.25 + dt).contains(&appear_time));
+    /// }
+    /// ```
+    ///
+    /// A stalled frame, e.g. after the window was unfocused, only advances animations by a
+    /// tenth of a second, so toasts still animate rather than popping open or closed at once.
+    /// ```
+    /// use egui_notify::{ToastPhase, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut t = Toasts::default();
+    /// t.info("Hello").set_duration(None);
+    /// let mut frame = |t: &mut Toasts, time: f64| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    ///     t.iter().next().map(|toast| toast.phase())
+    /// };
+    ///
+    /// frame(&mut t, 0.);
+    /// assert_eq!(frame(&mut t, 10.), Some(ToastPhase::Appearing));
+    /// for time in [10.1, 10.2, 10.3] {
+    ///     frame(&mut t, time);
+    /// }
+    /// assert_eq!(frame(&mut t, 10.4), Some(ToastPhase::Idle));
+    ///
+    /// t.dismiss_all_toasts();
+    /// assert_eq!(frame(&mut t, 20.), Some(ToastPhase::Disappearing));
+    /// ```
+    ///
+    /// Durations count down by the input time elapsed between frames too, so the progress bar
+    /// doesn't lag behind when frames are sparse, e.g. when repaints are throttled.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Hello").set_duration(Some(Duration::from_secs(4)));
+    /// let mut frame = |time, show: bool| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     ctx.run(input, |ctx| if show { t.show(ctx) }).shapes
+    /// };
+    ///
+    /// frame(0., true);
+    /// frame(1., false);
+    /// let mut rects: Vec<_> = frame(2., true).iter().filter_map(|s| match &s.shape {
+    ///     egui::Shape::Rect(rect) => Some(rect.rect.width()),
+    ///     _ => None,
+    /// }).collect();
+    /// rects.sort_by(f32::total_cmp);
+    /// let elapsed = rects[0] / rects[1];
+    /// assert!((elapsed - 0.5).abs() < 0.01);
+    /// ```
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_toasts(ctx, false);
+    }
+
+    /// Displays toast queue like [`Toasts::show`], backing every painted toast with a widget.
+    /// Returns the widgets' responses along with the index of their toast in [`Toasts::iter`],
+    /// so tooltips or context menus can be attached to them.
+    /// ```
+    /// use egui::{Event, PointerButton};
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Click me");
+    /// let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///     let mut responses = vec![];
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| responses = t.show_and_interact(ctx));
+    ///     responses
+    /// };
+    ///
+    /// let pos = frame(&mut t, vec![])[0].1.rect.center();
+    /// frame(&mut t, vec![Event::PointerMoved(pos)]);
+    /// let button = |pressed| Event::PointerButton {
+    ///     pos,
+    ///     button: PointerButton::Primary,
+    ///     pressed,
+    ///     modifiers: Default::default(),
+    /// };
+    /// frame(&mut t, vec![button(true)]);
+    /// let responses = frame(&mut t, vec![button(false)]);
+    /// assert_eq!(responses[0].0, 0);
+    /// assert!(responses[0].1.clicked());
+    /// ```
+    ///
+    /// Hovering and clicking go by where toasts are painted, so the cross of a toast sliding
+    /// in can only be clicked where it currently is, not where it's going to rest.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui::{Event, PointerButton, Pos2};
+    /// use egui_notify::Toasts;
+    ///
+    /// let cross_of = |rect: egui::Rect| rect.right_center() - egui::vec2(20., 0.);
+    /// let resting = {
+    ///     let mut t = Toasts::default().suppress_initial_animation(true);
+    ///     t.info("Hello");
+    ///     let mut rect = egui::Rect::NOTHING;
+    ///     let _ = egui::Context::default()
+    ///         .run(Default::default(), |ctx| rect = t.show_and_interact(ctx)[0].1.rect);
+    ///     cross_of(rect)
+    /// };
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().with_appear_duration(Duration::from_secs(10));
+    /// t.info("Hello");
+    /// let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let mut rect = egui::Rect::NOTHING;
+    ///     let _ = ctx.run(input, |ctx| rect = t.show_and_interact(ctx)[0].1.rect);
+    ///     cross_of(rect)
+    /// };
+    /// let click = |pos: Pos2| {
+    ///     let button = |pressed| Event::PointerButton {
+    ///         pos,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Default::default(),
+    ///     };
+    ///     vec![Event::PointerMoved(pos), button(true), button(false)]
+    /// };
+    ///
+    /// let sliding = frame(&mut t, vec![]);
+    /// assert!(sliding.x > resting.x);
+    /// frame(&mut t, click(resting));
+    /// let sliding = frame(&mut t, vec![Event::PointerGone]);
+    /// assert_eq!(t.debug_stats().disappearing, 0);
+    ///
+    /// frame(&mut t, click(sliding));
+    /// frame(&mut t, vec![Event::PointerGone]);
+    /// assert_eq!(t.debug_stats().disappearing, 1);
+    /// ```
+    pub fn show_and_interact(&mut self, ctx: &Context) -> Vec<(usize, Response)> {
+        self.show_toasts(ctx, true)
+    }
+
+    fn show_toasts(&mut self, ctx: &Context, interact: bool) -> Vec<(usize, Response)> {
+        for (_, channel) in &mut self.channels {
+            channel.show_toasts(ctx, false);
+        }
+
+        let mut responses = vec![];
+        #[cfg(any(test, feature = "testing"))]
+        self.painted.clear();
+        self.input_time = ctx.input(|i| i.time);
+        let screen_rect = ctx.screen_rect();
+        // Happens on the first frame of some integrations, and in headless contexts
+        if !screen_rect.is_finite() || !screen_rect.is_positive() {
+            self.last_time = None;
+            return responses;
+        }
+        // Count down by the input time elapsed since the last frame rather than `stable_dt`,
+        // which egui predicts when no immediate repaint was requested, so sparse frames
+        // don't make countdowns lag. Time only counts from frames that scheduled a wake,
+        // otherwise a single frame's worth passes.
+        let time = self.input_time;
+        let dt = match self.last_time {
+            Some(last_time) if time > last_time => (time - last_time) as f32,
+            _ => ctx.input(|i| i.stable_dt).min(MAX_ANIMATION_DT),
+        };
+        let safe_rect = self.safe_rect(ctx);
+        let mut toast_anchor = self.anchor.pos_in_rect_with_margin(&safe_rect, self.margin);
+        let toasts_layer_id = self.id;
+        let painter = ctx.layer_painter(LayerId::new(self.order, toasts_layer_id));
+        let mut dismiss = Vec::new();
+
+        if !self.shown {
+            self.shown = true;
+            if self.suppress_initial_animation {
+                for toast in self
+                    .toasts
+                    .iter_mut()
+                    .filter(|t| t.state.appearing() && t.start_delay.is_none())
+                {
+                    toast.value = 1.;
+                    toast.state = ToastState::Idle;
+                }
+            }
+        }
+
+        // Add toasts sent from other threads
+        let incoming: Vec<_> = self
+            .incoming
+            .as_ref()
+            .map(|r| r.try_iter().collect())
+            .unwrap_or_default();
+        for (level, caption) in incoming {
+            self.add(self.base_toast(caption).with_level(level));
+        }
 
         // Remove disappeared toasts
-        self.toasts.retain(|t| !t.state.disappeared());
+        let (mut removed, kept): (Vec<_>, _) = std::mem::take(&mut self.toasts)
+            .into_iter()
+            .partition(|t| t.state.disappeared());
+        self.toasts = kept;
+        removed.sort_by_key(|t| t.add_index);
+        self.keep_dismissed(removed);
 
         // Start disappearing expired toasts
         self.toasts.iter_mut().for_each(|t| {
             if let Some((_initial_d, current_d)) = t.options.duration {
                 if current_d <= 0. {
-                    t.state = ToastState::Disapper
+                    t.dismiss();
                 }
             }
         });
 
-        // `held` used to prevent sticky removal
-        if ctx.input(|i| i.pointer.primary_released()) {
-            self.held = false;
-        }
-
         let visuals = ctx.style().visuals.widgets.noninteractive;
         let mut repaint = false;
+        let anim_durations = (self.appear_duration, self.disappear_duration);
+        // Clamped so a stalled frame doesn't pop toasts fully open or closed at once
+        let anim_dt = dt.min(MAX_ANIMATION_DT);
+        let mut stats = ToastStats {
+            total: self.toasts.len(),
+            ..Default::default()
+        };
+        // Layers of a higher order are painted above the toasts, so they get the pointer
+        let hover_pos = ctx.input(|i| i.pointer.hover_pos()).filter(|&pos| {
+            ctx.layer_id_at(pos)
+                .map_or(true, |layer| layer.order <= self.order)
+        });
+        self.hovered_region = None;
+        self.clicked_actions.clear();
+        let mut placed = Vec::with_capacity(self.toasts.len());
+
+        // Keep the stack clear of the banner when both are at the top
+        let banner_height = self.show_banner(ctx, &painter, &visuals, anim_dt, &mut repaint);
+        if self.anchor.y() == Align::Min {
+            toast_anchor.y += banner_height;
+        }
+
+        // The header goes above the stack, so first when stacking downwards and last otherwise
+        let header_first = self.anchor.y() == Align::Min;
+        if header_first {
+            let height = self.show_header(ctx, &painter, &visuals, toast_anchor, hover_pos);
+            self.anchor.offset_height(&mut toast_anchor, height);
+        }
+
+        let queued = self.queued();
 
         for (i, toast) in self.toasts.iter_mut().enumerate() {
-            let toast_id = toasts_layer_id.with(toast.timestamp).with(toast.add_index);
+            let toast_id = match toast.id {
+                Some(id) => toasts_layer_id.with(id),
+                None => toasts_layer_id.with(toast.timestamp).with(toast.add_index),
+            };
             let mut disconnect = false;
             if let Some(update_res) = toast.update_reciever.as_ref() {
                 match update_res.try_recv() {
@@ -221,7 +2848,8 @@ impl Toasts {
                             toast.fallback_options = Some(options);
                         }
                         if let Some(caption) = update.caption {
-                            toast.caption = caption
+                            toast.caption = caption;
+                            toast.galley = None;
                         }
                         if let Some(fallback_options) = update.fallback_options {
                             toast.fallback_options = Some(fallback_options);
@@ -229,13 +2857,28 @@ impl Toasts {
                         if let Some(level) = update.level {
                             toast.options.level = level
                         }
+                        if let Some(progress) = update.progress {
+                            toast.task_progress = Some(progress.clamp(0., 1.));
+                        }
+                        if let Some((level, caption, duration)) = update.result {
+                            toast.set_level(level);
+                            toast.caption = caption;
+                            toast.galley = None;
+                            toast.options.set_duration(duration);
+                            toast.task_progress = None;
+                            toast.loading = false;
+                            toast.fallback_options = None;
+                            disconnect = true;
+                        }
                     }
                     Err(TryRecvError::Disconnected) => {
                         disconnect = true;
+                        toast.loading = false;
+                        toast.task_progress = None;
                         if let Some(fallback_options) = toast.fallback_options.take() {
                             toast.options = fallback_options;
                         } else {
-                            dismiss = Some(i);
+                            dismiss.push(i);
                         }
                     }
                     _ => {}
@@ -246,216 +2889,534 @@ impl Toasts {
                 toast.update_reciever = None;
             }
 
-            // Decrease duration if idling
+            if let Some(binding) = toast.caption_binding.as_ref() {
+                let caption = (binding.0)();
+                if caption != toast.caption {
+                    toast.caption = caption;
+                    toast.galley = None;
+                }
+            }
+
+            if is_queued(toast, &queued) {
+                if toast.state.disappearing() {
+                    toast.finish_disappearing();
+                } else {
+                    stats.queued += 1;
+                }
+                toast.toast_hovered = false;
+                continue;
+            }
+
+            // Hold delayed toasts back until their delay elapses
+            if let Some(delay) = toast.start_delay.as_mut() {
+                if toast.state.disappearing() {
+                    toast.finish_disappearing();
+                    continue;
+                }
+                *delay -= dt;
+                repaint = true;
+                if *delay > 0. {
+                    continue;
+                }
+                toast.start_delay = None;
+            }
+            toast.shown_for += dt;
+
+            // Decrease duration if idling, or appearing when that counts too
+            let counting = match self.duration_counting {
+                DurationCounting::IdleOnly => toast.state.idling(),
+                DurationCounting::FromAppear => toast.state.idling() || toast.state.appearing(),
+            };
             if let Some((_, d)) = toast.options.duration.as_mut() {
-                if toast.state.idling() && !toast.toast_hovered {
-                    *d -= ctx.input(|i| i.stable_dt);
+                if counting && !toast.toast_hovered {
+                    *d -= dt;
                     repaint = true;
                 }
             }
 
-            // Create toast label
-            let caption_galley = ctx.fonts(|f| {
-                f.layout(
-                    toast.caption.clone(),
-                    FontId::proportional(16.),
-                    visuals.fg_stroke.color,
-                    f32::INFINITY,
-                )
-            });
+            // Count down a scheduled dismissal
+            if let Some(delay) = toast.dismiss_delay.as_mut() {
+                if !toast.state.disappearing() {
+                    *delay -= dt;
+                    repaint = true;
+                    if *delay <= 0. {
+                        toast.dismiss_delay = None;
+                        toast.dismiss();
+                    }
+                }
+            }
 
-            let (caption_width, caption_height) =
-                (caption_galley.rect.width(), caption_galley.rect.height());
-
-            let line_count = toast.caption.chars().filter(|c| *c == '\n').count() + 1;
-            let icon_width = caption_height / line_count as f32;
-
-            // Create toast icon
-            let icon_font = FontId::proportional(icon_width);
-            let icon_galley = if !matches!(toast.options.level, ToastLevel::None) {
-                Some(ctx.fonts(|f| {
-                    f.layout(
-                        toast.options.level.to_string(),
-                        icon_font,
-                        toast.options.level.color(),
-                        f32::INFINITY,
-                    )
-                }))
-            } else {
-                None
-            };
+            if toast
+                .dismiss_condition
+                .as_ref()
+                .is_some_and(|condition| (condition.0)())
+            {
+                toast.dismiss_condition = None;
+                toast.dismiss();
+            }
 
-            let (action_width, action_height) = if let Some(icon_galley) = icon_galley.as_ref() {
-                (icon_galley.rect.width(), icon_galley.rect.height())
-            } else {
-                (0., 0.)
-            };
+            // Skip laying out toasts stacked past the edge of the screen, keeping their slot
+            if toast.follow.is_none() {
+                let last_rect = self.anchor.align_size_to_pos(toast_anchor, toast.size());
+                if !screen_rect.intersects(last_rect) {
+                    animated_rect(ctx, toast_id, self.anchor, toast_anchor, toast.size());
+                    let spacing = self.spacing_mode.spacing(self.spacing, i);
+                    let slot = stack_slot(toast, spacing, self.reserve_space);
+                    self.anchor.offset_height(&mut toast_anchor, slot);
+                    toast.toast_hovered = false;
+                    repaint |= toast.animate(anim_dt, anim_durations);
+                    continue;
+                }
+            }
 
-            // Create closing cross
-            let cross_galley = if toast.options.closable {
-                let cross_fid = FontId::proportional(icon_width);
-                let cross_galley = ctx.fonts(|f| {
-                    f.layout(
-                        "❌".into(),
-                        cross_fid,
-                        if toast.cross_hovered {
-                            lighter(visuals.fg_stroke.color)
-                        } else {
-                            visuals.fg_stroke.color
-                        },
-                        f32::INFINITY,
-                    )
-                });
-                Some(cross_galley)
-            } else {
-                None
+            let closable = toast.options.closable;
+            let (layout_cross, paint_cross) = match toast.options.close_visibility {
+                CloseButtonVisibility::Always => (closable, closable),
+                CloseButtonVisibility::OnHover { reserve_space } => (
+                    closable && (toast.toast_hovered || reserve_space),
+                    closable && toast.toast_hovered,
+                ),
             };
+            let mut layout = ToastLayout::new(
+                ctx,
+                toast,
+                &visuals,
+                layout_cross,
+                self.level_colors.get(toast.options.level),
+            );
+            layout.paint_cross = paint_cross;
+            let padding = toast.options.padding.unwrap_or(self.padding);
+            layout.fit_max_width(ctx, toast, &visuals, padding);
+            if self.full_width && toast.follow.is_none() {
+                let width = (safe_rect.width() - self.margin.x * 2.).max(0.);
+                layout.fill_width(ctx, toast, &visuals, padding, width);
+            }
+            stats.galleys += layout.galleys;
+            let size = layout.size(padding);
+            toast.width = size.x;
+            toast.height = size.y;
 
-            let (cross_width, cross_height) = if let Some(cross_galley) = cross_galley.as_ref() {
-                (cross_galley.rect.width(), cross_galley.rect.height())
+            let toast_rect = if let Some(follow) = toast.follow.as_ref() {
+                // Following toasts sit centered above their position, outside of the stack
+                let rise = toast.height * (1. - ease_in_cubic(toast.value)) / 2.;
+                let pos = (follow.0)() + vec2(0., rise);
+                Align2::CENTER_BOTTOM.anchor_rect(Rect::from_min_size(pos, toast.size()))
             } else {
-                (0., 0.)
+                let animation = match self.exit_animation {
+                    Some(exit) if toast.state.disappearing() => exit,
+                    _ => self.animation,
+                };
+                let transform = animation.transform(toast, self.anchor.side());
+                let rect = animated_rect(ctx, toast_id, self.anchor, toast_anchor, toast.size());
+                layout.opacity = transform.opacity;
+                Rect::from_center_size(
+                    rect.center() + transform.offset,
+                    rect.size() * transform.scale,
+                )
             };
 
-            let icon_x_padding = (0., 7.);
-            let cross_x_padding = (7., 0.);
+            if let Some((initial, current)) = toast.options.duration {
+                let fraction = current / initial;
+                if self.smooth_progress {
+                    let t = (dt * PROGRESS_SMOOTHING).min(1.);
+                    toast.progress += (fraction - toast.progress) * t;
+                } else {
+                    toast.progress = fraction;
+                }
+            }
 
-            let icon_width_padded = if icon_width == 0. {
-                0.
-            } else {
-                icon_width + icon_x_padding.0 + icon_x_padding.1
-            };
-            let cross_width_padded = if cross_width == 0. {
-                0.
-            } else {
-                cross_width + cross_x_padding.0 + cross_x_padding.1
-            };
+            if toast.loading && !toast.state.disappearing() {
+                repaint = true;
+            }
+
+            if toast.follow.is_none() {
+                let spacing = self.spacing_mode.spacing(self.spacing, i);
+                let slot = stack_slot(toast, spacing, self.reserve_space);
+                self.anchor.offset_height(&mut toast_anchor, slot);
+            }
 
-            toast.width =
-                icon_width_padded + caption_width + cross_width_padded + (self.padding.x * 2.);
-            toast.height =
-                action_height.max(caption_height).max(cross_height) + self.padding.y * 2.;
+            placed.push(PlacedToast {
+                index: i,
+                id: toast_id,
+                layout,
+                rect: toast_rect,
+                padding,
+            });
+        }
 
-            let anim_offset = toast.width * (1. - ease_in_cubic(toast.value));
-            let toast_pos_x = toast_anchor.x + anim_offset * self.anchor.side();
+        if !header_first {
+            self.show_header(ctx, &painter, &visuals, toast_anchor, hover_pos);
+        }
 
-            let toast_pos_y = ctx.animate_value_with_time(toast_id, toast_anchor.y, 0.1);
-            let toast_rect = self
-                .anchor
-                .align_size_to_pos(pos2(toast_pos_x, toast_pos_y), toast.size());
+        if let Some(color) = self.container_background {
+            let stack_rect = placed
+                .iter()
+                .filter(|p| self.toasts[p.index].follow.is_none())
+                .map(|p| p.rect)
+                .reduce(Rect::union);
+            if let Some(stack_rect) = stack_rect {
+                painter.rect_filled(
+                    stack_rect.expand(CONTAINER_MARGIN),
+                    Rounding::same(CONTAINER_MARGIN),
+                    color,
+                );
+            }
+        }
 
-            let toast_rect_rounding = Rounding::same(4.);
-            let mut toast_shadow = Shadow::small_dark();
+        match self.paint_order {
+            PaintOrder::Stack => {}
+            PaintOrder::NewestOnTop => placed.sort_by_key(|p| self.toasts[p.index].add_index),
+            PaintOrder::OldestOnTop => {
+                placed.sort_by_key(|p| Reverse(self.toasts[p.index].add_index));
+            }
+        }
 
-            toast_shadow.color = toast_shadow.color.linear_multiply(0.5);
-            painter.add(toast_shadow.tessellate(toast_rect, toast_rect_rounding));
+        // Only the topmost toast under the pointer counts as hovered
+        let topmost = hover_pos.and_then(|pos| {
+            placed
+                .iter()
+                .rev()
+                .find(|p| p.rect.contains(pos))
+                .map(|p| p.index)
+        });
 
-            // Draw background
-            painter.rect(
-                toast_rect,
-                Rounding::same(4.),
-                visuals.bg_fill,
-                Stroke::new(
-                    if toast.state.disappearing() { 0. } else { 1. },
-                    toast.options.level.color(),
-                ),
-            );
+        // Paint after placing every toast so the paint order can differ from the stack order
+        for PlacedToast {
+            index: i,
+            id: toast_id,
+            mut layout,
+            rect: toast_rect,
+            padding,
+        } in placed
+        {
+            let toast = &mut self.toasts[i];
 
-            if toast.options.show_progress_bar {
-                if let Some((initial, current)) = toast.options.duration {
-                    if !toast.state.disappearing() {
-                        let mut duration_rect = toast_rect;
-                        duration_rect.set_left(
-                            toast_rect.right() - (1. - (current / initial)) * toast_rect.width(),
-                        );
-                        painter.rect_stroke(
-                            duration_rect,
-                            Rounding::same(4.),
-                            Stroke::new(2., visuals.bg_fill),
+            let selectable = self.selectable_text && toast.galley.is_none();
+            layout.paint_caption = !selectable;
+            #[cfg(any(test, feature = "testing"))]
+            let caption_galley = layout.caption.clone();
+            let painted = layout.paint(&painter, toast_rect, toast, padding, &visuals);
+            let cross_rect = painted.cross;
+            #[cfg(any(test, feature = "testing"))]
+            self.painted.push(PaintedLayout {
+                index: i,
+                rect: toast_rect,
+                caption: painted.caption,
+                caption_galley,
+                icon: painted.icon,
+                cross: painted.cross,
+                actions: painted.actions.clone(),
+            });
+
+            if selectable && !toast.state.disappearing() {
+                Area::new(toast_id.with("caption"))
+                    .order(self.order)
+                    .fixed_pos(painted.caption.min)
+                    .show(ctx, |ui| {
+                        ui.add(
+                            TextEdit::multiline(&mut toast.displayed_caption().as_str())
+                                .frame(false)
+                                .margin(Vec2::ZERO)
+                                .font(FontId::proportional(16.))
+                                .text_color(visuals.fg_stroke.color)
+                                .desired_width(painted.caption.width())
+                                .desired_rows(1),
                         );
-                    }
-                }
+                    });
             }
 
-            // Paint icon
-            if let Some((icon_galley, true)) =
-                icon_galley.zip(Some(toast.options.level != ToastLevel::None))
-            {
-                let oy = toast.height / 2. - action_height / 2.;
-                let ox = self.padding.x + icon_x_padding.0;
-                painter.galley(toast_rect.min + vec2(ox, oy), icon_galley);
+            stats.visible += 1;
+            if toast.state.disappearing() {
+                stats.disappearing += 1;
+            }
+            if toast.update_reciever.is_some() {
+                stats.pending += 1;
             }
 
-            // Paint caption
-            let oy = toast.height / 2. - caption_height / 2.;
-            let o_from_icon = if action_width == 0. {
-                0.
-            } else {
-                action_width + icon_x_padding.1
-            };
-            let o_from_cross = if cross_width == 0. {
-                0.
-            } else {
-                cross_width + cross_x_padding.0
-            };
-            let ox = (toast.width / 2. - caption_width / 2.) + o_from_icon / 2. - o_from_cross / 2.;
-            painter.galley(toast_rect.min + vec2(ox, oy), caption_galley);
-
-            // Paint cross
-            if let Some(cross_galley) = cross_galley {
-                let cross_rect = cross_galley.rect;
-                let oy = toast.height / 2. - cross_height / 2.;
-                let ox = toast.width - cross_width - cross_x_padding.1 - self.padding.x;
-                let cross_pos = toast_rect.min + vec2(ox, oy);
-                painter.galley(cross_pos, cross_galley);
-
-                let cross_screen_rect = Rect {
-                    max: cross_pos + cross_rect.max.to_vec2(),
-                    min: cross_pos,
-                };
-
-                if let Some(hover_pos) = ctx.input(|i| i.pointer.hover_pos()) {
-                    toast.toast_hovered = toast_rect.contains(hover_pos);
-                    toast.cross_hovered = cross_screen_rect.contains(hover_pos);
+            let hovered = topmost == Some(i);
+            let action_at = |pos: Pos2| painted.actions.iter().position(|r| r.contains(pos));
+            toast.action_hovered = hover_pos.filter(|_| hovered).and_then(action_at);
+            if let Some(pos) = hover_pos.filter(|_| hovered) {
+                if cross_rect.is_some_and(|rect| rect.contains(pos)) {
+                    self.hovered_region = Some(ToastRegion::Cross);
+                    ctx.set_cursor_icon(CursorIcon::PointingHand);
+                } else if let Some(action) = toast.action_hovered {
+                    let id = toast.options.actions[action].id;
+                    self.hovered_region = Some(ToastRegion::Action(id));
+                    ctx.set_cursor_icon(CursorIcon::PointingHand);
+                } else {
+                    self.hovered_region = Some(ToastRegion::Body);
+                    if toast.on_click.is_some() {
+                        ctx.set_cursor_icon(CursorIcon::PointingHand);
+                    }
                 }
 
-                if let Some(click_pos) = ctx.input(|i| i.pointer.press_origin()) {
-                    if cross_screen_rect.contains(click_pos) && !self.held {
-                        dismiss = Some(i);
-                        self.held = true;
-                    }
+                if self.hover_info {
+                    show_hover_info(ctx, toast_id.with("hover_info"), toast);
+                } else if toast.caption_truncated() {
+                    egui::show_tooltip_text(ctx, toast_id.with("caption"), &toast.caption);
                 }
             }
 
-            self.anchor
-                .offset_height(&mut toast_anchor, self.spacing + toast.height);
+            toast.toast_hovered = hovered;
+            if let Some(cross_screen_rect) = cross_rect {
+                toast.cross_hovered =
+                    hovered && hover_pos.is_some_and(|pos| cross_screen_rect.contains(pos));
 
-            // Animations
-            if toast.state.appearing() {
-                repaint = true;
-                toast.value += ctx.input(|i| i.stable_dt) * (self.speed);
+                let armed = self.dismiss_grace.map_or(true, |grace| {
+                    !toast.state.appearing() && toast.shown_for >= grace
+                });
+                if hovered
+                    && armed
+                    && clicked(ctx, &mut self.pressed, toast_id, |pos| {
+                        cross_screen_rect.contains(pos)
+                    })
+                {
+                    dismiss.push(i);
+                }
+            }
 
-                if toast.value >= 1. {
-                    toast.value = 1.;
-                    toast.state = ToastState::Idle;
+            for (action, action_rect) in toast.options.actions.iter().zip(&painted.actions) {
+                let action_id = toast_id.with("action").with(action.id);
+                if hovered
+                    && !toast.state.disappearing()
+                    && clicked(ctx, &mut self.pressed, action_id, |pos| {
+                        action_rect.contains(pos)
+                    })
+                {
+                    self.clicked_actions.push((toast.timestamp, action.id));
                 }
-            } else if toast.state.disappearing() {
-                repaint = true;
-                toast.value -= ctx.input(|i| i.stable_dt) * (self.speed);
+            }
 
-                if toast.value <= 0. {
-                    toast.state = ToastState::Disappeared;
+            if let Some(on_click) = toast.on_click.as_mut() {
+                let over_body = |pos| {
+                    toast_rect.contains(pos)
+                        && !cross_rect.is_some_and(|rect| rect.contains(pos))
+                        && action_at(pos).is_none()
+                };
+                if hovered
+                    && !toast.state.disappearing()
+                    && clicked(ctx, &mut self.pressed, toast_id.with("body"), over_body)
+                {
+                    (on_click.0)();
                 }
             }
+
+            if interact {
+                let response = Area::new(toast_id.with("interact"))
+                    .order(self.order)
+                    .fixed_pos(toast_rect.min)
+                    .show(ctx, |ui| {
+                        ui.allocate_rect(toast_rect, Sense::click_and_drag())
+                    })
+                    .inner;
+                responses.push((i, response));
+            }
+
+            // Animations
+            repaint |= toast.animate(anim_dt, anim_durations);
         }
 
+        self.stats = stats;
+
         if repaint {
             ctx.request_repaint();
         }
+        self.last_time = (repaint || self.next_wake().is_some()).then_some(time);
 
-        if let Some(i) = dismiss {
+        for i in dismiss {
             self.toasts[i].dismiss();
         }
+
+        if ctx.input(|i| i.pointer.primary_released()) {
+            self.pressed = None;
+        }
+
+        responses
+    }
+
+    /// Paints the toasts into `ui` one below the other, fully shown and frozen in time,
+    /// e.g. for deterministic screenshots. Disappearing, delayed and following toasts are
+    /// left out, and nothing is animated or counted down.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default();
+    /// t.info("Saved");
+    /// t.loading("Uploading");
+    /// let mut render = |time| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| t.show_snapshot(ui));
+    ///     });
+    ///     output.shapes
+    /// };
+    /// let first = render(1.0);
+    /// assert_eq!(first, render(2.5));
+    /// ```
+    pub fn show_snapshot(&self, ui: &mut Ui) -> Response {
+        let visuals = ui.style().visuals.widgets.noninteractive;
+        ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = self.spacing;
+            let shown = self.toasts.iter().filter(|t| {
+                !t.state.disappearing()
+                    && !t.state.disappeared()
+                    && t.start_delay.is_none()
+                    && t.follow.is_none()
+            });
+            for toast in shown {
+                let closable = toast.options.closable;
+                let (layout_cross, paint_cross) = match toast.options.close_visibility {
+                    CloseButtonVisibility::Always => (closable, closable),
+                    CloseButtonVisibility::OnHover { reserve_space } => {
+                        (closable && reserve_space, false)
+                    }
+                };
+                let mut layout = ToastLayout::new(
+                    ui.ctx(),
+                    toast,
+                    &visuals,
+                    layout_cross,
+                    self.level_colors.get(toast.options.level),
+                );
+                layout.paint_cross = paint_cross;
+                layout.frozen = true;
+                let padding = toast.options.padding.unwrap_or(self.padding);
+                layout.fit_max_width(ui.ctx(), toast, &visuals, padding);
+                let (rect, _) = ui.allocate_exact_size(layout.size(padding), Sense::hover());
+                layout.paint(ui.painter(), rect, toast, padding, &visuals);
+            }
+        })
+        .response
+    }
+
+    /// Area of the screen toasts are placed in, clear of the safe-area insets.
+    fn safe_rect(&self, ctx: &Context) -> Rect {
+        let screen_rect = ctx.screen_rect();
+        Rect::from_min_max(
+            screen_rect.min + self.safe_area.left_top(),
+            screen_rect.max - self.safe_area.right_bottom(),
+        )
+    }
+
+    /// Paints the header at `pos`, dismissing every toast when its button is clicked.
+    /// Returns the height it takes up in the stack, including spacing.
+    fn show_header(
+        &mut self,
+        ctx: &Context,
+        painter: &Painter,
+        visuals: &WidgetVisuals,
+        pos: Pos2,
+        hover_pos: Option<Pos2>,
+    ) -> f32 {
+        let Some(title) = self.header.clone() else {
+            return 0.;
+        };
+        let stacked = || self.toasts.iter().filter(|t| t.follow.is_none());
+        if stacked().all(|t| t.state.disappearing()) {
+            return 0.;
+        }
+
+        let font = FontId::proportional(16.);
+        let title = ctx.fonts(|f| f.layout_no_wrap(title, font.clone(), visuals.fg_stroke.color));
+        let button =
+            ctx.fonts(|f| f.layout_no_wrap("Clear all".into(), font, visuals.fg_stroke.color));
+        let content = vec2(
+            title.size().x + HEADER_BUTTON_GAP + button.size().x,
+            title.size().y.max(button.size().y),
+        );
+        let width = stacked()
+            .map(|t| t.width)
+            .fold(content.x + self.padding.x * 2., f32::max);
+        let size = vec2(width, content.y + self.padding.y * 2.);
+
+        let header_id = self.id.with("header");
+        let rect = animated_rect(ctx, header_id, self.anchor, pos, size);
+        let inner = rect.shrink2(self.padding);
+        let button_rect = Align2::RIGHT_CENTER.align_size_within_rect(button.size(), inner);
+
+        let hovered = hover_pos.is_some_and(|pos| button_rect.contains(pos));
+        if hovered {
+            ctx.set_cursor_icon(CursorIcon::PointingHand);
+        }
+        let button_color = if hovered {
+            lighter(visuals.fg_stroke.color)
+        } else {
+            visuals.fg_stroke.color
+        };
+
+        painter.rect_filled(rect, Rounding::same(4.), visuals.bg_fill);
+        painter.galley(
+            Align2::LEFT_CENTER
+                .align_size_within_rect(title.size(), inner)
+                .min,
+            title,
+        );
+        painter.galley_with_color(button_rect.min, button, button_color);
+
+        if hovered
+            && clicked(ctx, &mut self.pressed, header_id, |pos| {
+                button_rect.contains(pos)
+            })
+        {
+            self.dismiss_all_toasts();
+        }
+        size.y + self.spacing
+    }
+
+    /// Paints the banner, returning the height it takes up at the top of the screen.
+    fn show_banner(
+        &mut self,
+        ctx: &Context,
+        painter: &Painter,
+        visuals: &WidgetVisuals,
+        anim_dt: f32,
+        repaint: &mut bool,
+    ) -> f32 {
+        if self.banner.as_ref().is_some_and(|b| b.state.disappeared()) {
+            self.banner = None;
+        }
+        let screen_rect = self.safe_rect(ctx);
+        let Some(banner) = self.banner.as_mut() else {
+            return 0.;
+        };
+
+        let layout = ToastLayout::new(
+            ctx,
+            banner,
+            visuals,
+            banner.options.closable,
+            self.level_colors.get(banner.options.level),
+        );
+        let padding = banner.options.padding.unwrap_or(self.padding);
+        banner.width = (screen_rect.width() - self.margin.x * 2.).max(0.);
+        banner.height = layout.size(padding).y;
+
+        let visible = ease_in_cubic(banner.value);
+        let slide = (banner.height + self.margin.y) * (1. - visible);
+        let rect = Rect::from_min_size(
+            screen_rect.min + self.margin - vec2(0., slide),
+            banner.size(),
+        );
+        let cross_rect = layout.paint(painter, rect, banner, padding, visuals).cross;
+
+        if let Some(cross_rect) = cross_rect {
+            if let Some(hover_pos) = ctx.input(|i| i.pointer.hover_pos()) {
+                banner.cross_hovered = cross_rect.contains(hover_pos);
+            }
+
+            let banner_id = self.id.with("banner");
+            if clicked(ctx, &mut self.pressed, banner_id, |pos| {
+                cross_rect.contains(pos)
+            }) {
+                banner.dismiss();
+            }
+        }
+
+        let anim_durations = (self.appear_duration, self.disappear_duration);
+        *repaint |= banner.animate(anim_dt, anim_durations);
+        (banner.height + self.spacing) * visible
     }
 }
 
@@ -465,6 +3426,668 @@ impl Default for Toasts {
     }
 }
 
+// Compile-time check that `Toasts` stays `Send`.
+const _: () = {
+    const fn assert_send<T: Send>() {}
+    assert_send::<Toasts>();
+};
+
+const ICON_X_PADDING: (f32, f32) = (0., 7.);
+const CROSS_X_PADDING: (f32, f32) = (7., 0.);
+const ACTION_PADDING: Vec2 = vec2(6., 2.);
+const ACTION_SPACING: f32 = 6.;
+
+/// Galleys making up a single toast, laid out independently of where it is painted.
+struct ToastLayout {
+    caption: Arc<Galley>,
+    /// Width of the block the caption is centered in, at least as wide as the caption.
+    caption_width: f32,
+    icon: Option<Arc<Galley>>,
+    cross: Option<Arc<Galley>>,
+    actions: Vec<Arc<Galley>>,
+    color: Color32,
+    icon_width: f32,
+    /// Whether the caption galley is painted, or left to a widget.
+    paint_caption: bool,
+    /// Whether the cross is painted, or only has its space reserved.
+    paint_cross: bool,
+    /// Opacity everything is painted with.
+    opacity: f32,
+    /// Whether to paint without anything depending on time.
+    frozen: bool,
+    /// Number of galleys laid out to build this layout.
+    galleys: usize,
+}
+
+impl ToastLayout {
+    fn new(
+        ctx: &Context,
+        toast: &Toast,
+        visuals: &WidgetVisuals,
+        closable: bool,
+        color: Color32,
+    ) -> Self {
+        // Create toast label, unless a prebuilt galley was provided
+        let caption = match toast.galley.clone() {
+            Some(galley) => galley,
+            None => layout_caption(ctx, toast, visuals, f32::INFINITY),
+        };
+        let caption_width = caption.rect.width();
+
+        let line_count = caption.rows.len().max(1);
+        let icon_width = caption.rect.height() / line_count as f32;
+
+        // Create toast icon
+        let icon_text = match &toast.options.icon {
+            Some(icon) => Some(icon.clone()),
+            None if !matches!(toast.options.level, ToastLevel::None) => {
+                Some(toast.options.level.to_string())
+            }
+            None => None,
+        };
+        let icon = icon_text.map(|text| {
+            ctx.fonts(|f| {
+                f.layout(
+                    text,
+                    FontId::proportional(icon_width),
+                    toast.options.icon_color.unwrap_or(color),
+                    f32::INFINITY,
+                )
+            })
+        });
+
+        // Create closing cross
+        let cross = if closable {
+            let cross_color = toast.options.cross_color.unwrap_or(visuals.fg_stroke.color);
+            Some(ctx.fonts(|f| {
+                f.layout(
+                    "❌".into(),
+                    FontId::proportional(icon_width),
+                    if toast.cross_hovered {
+                        lighter(cross_color)
+                    } else {
+                        cross_color
+                    },
+                    f32::INFINITY,
+                )
+            }))
+        } else {
+            None
+        };
+
+        // Create action buttons
+        let actions: Vec<_> = toast
+            .options
+            .actions
+            .iter()
+            .map(|action| {
+                ctx.fonts(|f| {
+                    f.layout_no_wrap(
+                        action.label.clone(),
+                        FontId::proportional(14.),
+                        visuals.fg_stroke.color,
+                    )
+                })
+            })
+            .collect();
+
+        let galleys = usize::from(toast.galley.is_none())
+            + usize::from(icon.is_some())
+            + usize::from(cross.is_some())
+            + actions.len();
+
+        Self {
+            caption,
+            caption_width,
+            icon,
+            cross,
+            actions,
+            color,
+            icon_width,
+            paint_caption: true,
+            paint_cross: true,
+            opacity: 1.,
+            frozen: false,
+            galleys,
+        }
+    }
+
+    /// Wraps the caption so the toast is no wider than its `max_width`.
+    /// A wrapped caption takes up the full width left next to the icon and cross.
+    fn fit_max_width(
+        &mut self,
+        ctx: &Context,
+        toast: &Toast,
+        visuals: &WidgetVisuals,
+        padding: Vec2,
+    ) {
+        if let Some(max_width) = toast.options.max_width {
+            if self.size(padding).x > max_width {
+                self.fill_width(ctx, toast, visuals, padding, max_width);
+            }
+        }
+    }
+
+    /// Sizes the toast to exactly `width`, wrapping the caption if it doesn't fit.
+    fn fill_width(
+        &mut self,
+        ctx: &Context,
+        toast: &Toast,
+        visuals: &WidgetVisuals,
+        padding: Vec2,
+        width: f32,
+    ) {
+        let extra = width - self.size(padding).x;
+        self.caption_width = (self.caption_width + extra).max(0.);
+        if extra < 0. && toast.galley.is_none() {
+            self.caption = layout_caption(ctx, toast, visuals, self.caption_width);
+            self.galleys += 1;
+        }
+    }
+
+    /// Width taken up by the action buttons, including the spacing before each.
+    fn actions_width(&self) -> f32 {
+        self.actions
+            .iter()
+            .map(|g| g.size().x + ACTION_PADDING.x * 2. + ACTION_SPACING)
+            .sum()
+    }
+
+    fn size(&self, padding: Vec2) -> Vec2 {
+        let icon_size = galley_size(&self.icon);
+        let cross_size = galley_size(&self.cross);
+        let actions_height = self
+            .actions
+            .iter()
+            .map(|g| g.size().y + ACTION_PADDING.y * 2.)
+            .fold(0., f32::max);
+
+        // Icon-only toasts don't need the gap between the icon and the caption
+        let icon_width_padded = if self.icon_width == 0. {
+            0.
+        } else if self.caption.text().is_empty() {
+            self.icon_width + ICON_X_PADDING.0
+        } else {
+            self.icon_width + ICON_X_PADDING.0 + ICON_X_PADDING.1
+        };
+        let cross_width_padded = if cross_size.x == 0. {
+            0.
+        } else {
+            cross_size.x + CROSS_X_PADDING.0 + CROSS_X_PADDING.1
+        };
+
+        vec2(
+            icon_width_padded
+                + self.caption_width
+                + self.actions_width()
+                + cross_width_padded
+                + padding.x * 2.,
+            icon_size
+                .y
+                .max(self.caption.rect.height())
+                .max(cross_size.y)
+                .max(actions_height)
+                + padding.y * 2.,
+        )
+    }
+
+    /// Paints the toast into `rect`, returning where its parts ended up.
+    fn paint(
+        self,
+        painter: &Painter,
+        rect: Rect,
+        toast: &Toast,
+        padding: Vec2,
+        visuals: &WidgetVisuals,
+    ) -> PaintedToast {
+        let icon_size = galley_size(&self.icon);
+        let cross_size = galley_size(&self.cross);
+        let caption_size = self.caption.rect.size();
+        let actions_width = self.actions_width();
+
+        let rounding = Rounding::same(4.);
+        let mut shadow = Shadow::small_dark();
+        let fade = |color: Color32| fade_color(color, self.opacity);
+
+        shadow.color = shadow.color.linear_multiply(0.5 * self.opacity);
+        painter.add(shadow.tessellate(rect, rounding));
+
+        // Frozen toasts show their exact progress rather than the smoothed one
+        let progress = match (self.frozen, toast.options.duration) {
+            (true, Some((initial, current))) => current / initial,
+            _ => toast.progress,
+        };
+        let border_color = match (toast.options.expiry_warning, toast.options.duration) {
+            (Some((warning, threshold)), Some(_)) if progress < threshold => {
+                lerp_color(self.color, warning, 1. - progress / threshold)
+            }
+            _ => self.color,
+        };
+        let border_color = fade(border_color);
+
+        // Draw background
+        painter.rect(
+            rect,
+            rounding,
+            fade(visuals.bg_fill),
+            Stroke::new(
+                if toast.state.disappearing() { 0. } else { 1. },
+                border_color,
+            ),
+        );
+
+        if toast.options.show_progress_bar
+            && toast.options.duration.is_some()
+            && !toast.state.disappearing()
+        {
+            let mut duration_rect = rect;
+            duration_rect.set_left(rect.right() - (1. - progress) * rect.width());
+            painter.rect_stroke(
+                duration_rect,
+                rounding,
+                Stroke::new(2., fade(visuals.bg_fill)),
+            );
+        }
+
+        if let Some(fraction) = toast.task_progress.filter(|_| !toast.state.disappearing()) {
+            painter.hline(
+                rect.left()..=rect.left() + fraction * rect.width(),
+                rect.bottom() - 1.,
+                Stroke::new(2., fade(self.color)),
+            );
+        } else if toast.loading && !toast.state.disappearing() {
+            let segment_width = rect.width() / 3.;
+            let t = if self.frozen {
+                0.
+            } else {
+                (painter.ctx().input(|i| i.time) as f32 * 0.75).fract()
+            };
+            let left = rect.left() - segment_width + t * (rect.width() + segment_width);
+            painter.hline(
+                left.max(rect.left())..=(left + segment_width).min(rect.right()),
+                rect.bottom() - 1.,
+                Stroke::new(2., fade(self.color)),
+            );
+        }
+
+        let cross_on_left = toast.options.close_side == Side::Left;
+        let o_from_cross = if cross_size.x == 0. {
+            0.
+        } else {
+            cross_size.x + CROSS_X_PADDING.0
+        };
+
+        // Paint icon, inside of the cross when both are on the same side
+        let icon_on_right = toast.options.icon_side == Side::Right;
+        let icon_rect = self.icon.map(|icon| {
+            let oy = rect.height() / 2. - icon_size.y / 2.;
+            let ox = if icon_on_right {
+                let mut ox = rect.width() - padding.x - ICON_X_PADDING.0 - icon_size.x;
+                if !cross_on_left {
+                    ox -= o_from_cross;
+                }
+                ox
+            } else {
+                let mut ox = padding.x + ICON_X_PADDING.0;
+                if cross_on_left {
+                    ox += o_from_cross;
+                }
+                ox
+            };
+            let icon_rect = Rect::from_min_size(rect.min + vec2(ox, oy), icon_size);
+            paint_galley(painter, icon_rect.min, icon, self.opacity);
+            icon_rect
+        });
+
+        // Paint caption
+        let oy = rect.height() / 2. - caption_size.y / 2.;
+        let o_from_icon = if icon_size.x == 0. {
+            0.
+        } else {
+            icon_size.x + ICON_X_PADDING.1
+        };
+        let cross_shift = if cross_on_left {
+            o_from_cross / 2.
+        } else {
+            -o_from_cross / 2.
+        };
+        let icon_shift = if icon_on_right {
+            -o_from_icon / 2.
+        } else {
+            o_from_icon / 2.
+        };
+        let ox = (rect.width() / 2. - self.caption_width / 2.) + icon_shift + cross_shift
+            - actions_width / 2.;
+        let caption_rect = Rect::from_min_size(rect.min + vec2(ox, oy), caption_size);
+        if self.paint_caption {
+            paint_galley(painter, caption_rect.min, self.caption, self.opacity);
+        }
+
+        // Paint action buttons to the right of the caption
+        let mut action_x = rect.min.x + ox + self.caption_width;
+        let mut action_rects = Vec::with_capacity(self.actions.len());
+        for (i, action) in self.actions.into_iter().enumerate() {
+            action_x += ACTION_SPACING;
+            let size = action.size() + ACTION_PADDING * 2.;
+            let action_rect =
+                Rect::from_min_size(pos2(action_x, rect.center().y - size.y / 2.), size);
+            let fill = if toast.action_hovered == Some(i) {
+                fade(self.color.linear_multiply(0.25))
+            } else {
+                Color32::TRANSPARENT
+            };
+            painter.rect(
+                action_rect,
+                Rounding::same(3.),
+                fill,
+                Stroke::new(1., fade(self.color)),
+            );
+            paint_galley(
+                painter,
+                action_rect.min + ACTION_PADDING,
+                action,
+                self.opacity,
+            );
+            action_rects.push(action_rect);
+            action_x += size.x;
+        }
+
+        // Paint cross
+        let cross_rect = self.cross.filter(|_| self.paint_cross).map(|cross| {
+            let oy = rect.height() / 2. - cross_size.y / 2.;
+            let ox = if cross_on_left {
+                padding.x + CROSS_X_PADDING.1
+            } else {
+                rect.width() - cross_size.x - CROSS_X_PADDING.1 - padding.x
+            };
+            let cross_pos = rect.min + vec2(ox, oy);
+            let cross_rect = cross.rect;
+            paint_galley(painter, cross_pos, cross, self.opacity);
+
+            Rect {
+                max: cross_pos + cross_rect.max.to_vec2(),
+                min: cross_pos,
+            }
+        });
+
+        PaintedToast {
+            icon: icon_rect,
+            caption: caption_rect,
+            cross: cross_rect,
+            actions: action_rects,
+        }
+    }
+}
+
+/// A laid out toast waiting to be painted.
+struct PlacedToast {
+    index: usize,
+    id: Id,
+    layout: ToastLayout,
+    rect: Rect,
+    padding: Vec2,
+}
+
+/// Screen rects of the parts of a painted toast.
+struct PaintedToast {
+    #[cfg_attr(not(any(test, feature = "testing")), allow(dead_code))]
+    icon: Option<Rect>,
+    caption: Rect,
+    cross: Option<Rect>,
+    actions: Vec<Rect>,
+}
+
+/// Paints a single toast inline, without stacking, animations or a closing cross.
+/// ```
+/// use egui_notify::Toast;
+///
+/// # egui_notify::__run_test_ctx(|ctx| {
+/// egui::CentralPanel::default().show(ctx, |ui| {
+///     let toast = Toast::basic("Please fill in all fields").warning();
+///     ui.add(&toast);
+/// });
+/// # });
+/// ```
+impl Widget for &Toast {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let visuals = ui.style().visuals.widgets.noninteractive;
+        let mut layout =
+            ToastLayout::new(ui.ctx(), self, &visuals, false, self.options.level.color());
+        let padding = self.options.padding.unwrap_or(DEFAULT_PADDING);
+        layout.fit_max_width(ui.ctx(), self, &visuals, padding);
+        let (rect, response) = ui.allocate_exact_size(layout.size(padding), Sense::click());
+        if ui.is_rect_visible(rect) {
+            layout.paint(ui.painter(), rect, self, padding, &visuals);
+        }
+        response
+    }
+}
+
+/// Colors used for each [`ToastLevel`].
+#[derive(Debug, Clone, Copy)]
+struct LevelColors {
+    info: Color32,
+    warning: Color32,
+    error: Color32,
+    success: Color32,
+    debug: Color32,
+    trace: Color32,
+    none: Color32,
+}
+
+impl LevelColors {
+    fn get(&self, level: ToastLevel) -> Color32 {
+        match level {
+            ToastLevel::Info => self.info,
+            ToastLevel::Warning => self.warning,
+            ToastLevel::Error => self.error,
+            ToastLevel::Success => self.success,
+            ToastLevel::Debug => self.debug,
+            ToastLevel::Trace => self.trace,
+            ToastLevel::None => self.none,
+        }
+    }
+
+    fn set(&mut self, level: ToastLevel, color: Color32) {
+        let slot = match level {
+            ToastLevel::Info => &mut self.info,
+            ToastLevel::Warning => &mut self.warning,
+            ToastLevel::Error => &mut self.error,
+            ToastLevel::Success => &mut self.success,
+            ToastLevel::Debug => &mut self.debug,
+            ToastLevel::Trace => &mut self.trace,
+            ToastLevel::None => &mut self.none,
+        };
+        *slot = color;
+    }
+}
+
+impl Default for LevelColors {
+    fn default() -> Self {
+        Self {
+            info: ToastLevel::Info.color(),
+            warning: ToastLevel::Warning.color(),
+            error: ToastLevel::Error.color(),
+            success: ToastLevel::Success.color(),
+            debug: ToastLevel::Debug.color(),
+            trace: ToastLevel::Trace.color(),
+            none: ToastLevel::None.color(),
+        }
+    }
+}
+
+/// Error returned when a hex color string is not of the form `#rrggbb` or `#rrggbbaa`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseHexColorError(String);
+
+impl std::fmt::Display for ParseHexColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid hex color `{}`, expected #rrggbb or #rrggbbaa",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseHexColorError {}
+
+fn parse_hex_color(hex: &str) -> Result<Color32, ParseHexColorError> {
+    let err = || ParseHexColorError(hex.to_owned());
+    let digits = hex.strip_prefix('#').ok_or_else(err)?;
+    if !matches!(digits.len(), 6 | 8) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(err());
+    }
+
+    let channel = |i: usize| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).map_err(|_| err());
+    let alpha = if digits.len() == 8 { channel(3)? } else { 255 };
+    Ok(Color32::from_rgba_unmultiplied(
+        channel(0)?,
+        channel(1)?,
+        channel(2)?,
+        alpha,
+    ))
+}
+
+fn show_hover_info(ctx: &Context, id: Id, toast: &Toast) {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let age = now.saturating_sub(toast.timestamp) as f32 / 1000.;
+
+    egui::show_tooltip_at_pointer(ctx, id, |ui| {
+        if toast.caption_truncated() {
+            ui.label(&toast.caption);
+        }
+        ui.label(format!("level: {}", toast.options.level.name()));
+        ui.label(format!("age: {age:.1}s"));
+        match toast.options.duration {
+            Some((_, remaining)) => ui.label(format!("remaining: {:.1}s", remaining.max(0.))),
+            None => ui.label("remaining: never expires"),
+        };
+    });
+}
+
+/// Lays out the caption of `toast`, wrapping it at `wrap_width`.
+fn layout_caption(
+    ctx: &Context,
+    toast: &Toast,
+    visuals: &WidgetVisuals,
+    wrap_width: f32,
+) -> Arc<Galley> {
+    ctx.fonts(|f| {
+        f.layout(
+            toast.displayed_caption(),
+            FontId::proportional(16.),
+            visuals.fg_stroke.color,
+            wrap_width,
+        )
+    })
+}
+
+/// Normalizes line endings and replaces control characters that would otherwise be
+/// laid out as unknown glyphs, so the measured size matches the rendered text.
+/// Line breaks become spaces when `single_line` is set.
+fn sanitize_caption(caption: &str, single_line: bool) -> String {
+    let line_break = if single_line { ' ' } else { '\n' };
+    let mut sanitized = String::with_capacity(caption.len());
+    let mut chars = caption.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                sanitized.push(line_break);
+            }
+            '\n' => sanitized.push(line_break),
+            '\t' => sanitized.push_str(TAB_REPLACEMENT),
+            c if c.is_control() => {}
+            c => sanitized.push(c),
+        }
+    }
+    sanitized
+}
+
+/// Converts seconds to a [`Duration`], clamping negative ones to zero and saturating
+/// instead of panicking on ones too large to represent.
+pub(crate) fn seconds_to_duration(secs: f32) -> Duration {
+    Duration::try_from_secs_f32(secs.max(0.)).unwrap_or(Duration::MAX)
+}
+
+/// Cuts `caption` down to `max_chars` characters followed by an ellipsis, if it's longer.
+/// Characters are counted as graphemes, so none are split.
+fn truncate_caption(caption: &str, max_chars: Option<usize>) -> Option<String> {
+    let max_chars = max_chars?;
+    let (end, _) = caption.grapheme_indices(true).nth(max_chars)?;
+    Some(format!("{}…", &caption[..end]))
+}
+
+/// Whether the part `id` of a toast was clicked, i.e. the primary button was both pressed
+/// and released over it. `pressed` remembers which part the button was pressed on.
+fn clicked(ctx: &Context, pressed: &mut Option<Id>, id: Id, over: impl Fn(Pos2) -> bool) -> bool {
+    let (press, release, pos) = ctx.input(|i| {
+        (
+            i.pointer.primary_pressed(),
+            i.pointer.primary_released(),
+            i.pointer.interact_pos(),
+        )
+    });
+    let over = pos.is_some_and(over);
+    if press && over {
+        *pressed = Some(id);
+    }
+    release && over && *pressed == Some(id)
+}
+
+/// Multiplies the alpha of `color` by `opacity`.
+fn fade_color(color: Color32, opacity: f32) -> Color32 {
+    if opacity < 1. {
+        color.linear_multiply(opacity.max(0.))
+    } else {
+        color
+    }
+}
+
+/// Paints `galley`, fading its text by `opacity`.
+fn paint_galley(painter: &Painter, pos: Pos2, galley: Arc<Galley>, opacity: f32) {
+    match galley.job.sections.first() {
+        Some(section) if opacity < 1. => {
+            let color = fade_color(section.format.color, opacity);
+            painter.galley_with_color(pos, galley, color);
+        }
+        _ => painter.galley(pos, galley),
+    }
+}
+
+/// Whether `toast` waits for room under the visible cap, see [`Toasts::queued`].
+fn is_queued(toast: &Toast, queued: &[usize]) -> bool {
+    queued.contains(&toast.add_index)
+}
+
+/// Height a stacked toast takes up, shrinking as it disappears unless space is reserved.
+fn stack_slot(toast: &Toast, spacing: f32, reserve_space: bool) -> f32 {
+    if toast.state.disappearing() && !reserve_space {
+        (spacing + toast.height) * toast.value
+    } else {
+        spacing + toast.height
+    }
+}
+
+/// Rect of `size` placed at `pos` by `anchor`, sliding to its new place when either changes.
+fn animated_rect(ctx: &Context, id: Id, anchor: Align2, pos: Pos2, size: Vec2) -> Rect {
+    let animate = |id: Id, value: f32| ctx.animate_value_with_time(id, value, 0.1);
+    let pos = pos2(animate(id.with("x"), pos.x), animate(id, pos.y));
+    let align = vec2(
+        animate(id.with("align_x"), anchor.x().to_factor()),
+        animate(id.with("align_y"), anchor.y().to_factor()),
+    );
+    Rect::from_min_size(pos - mul_vec2(align, size), size)
+}
+
+fn galley_size(galley: &Option<Arc<Galley>>) -> Vec2 {
+    galley.as_ref().map_or(Vec2::ZERO, |g| g.rect.size())
+}
+
 fn mul_vec2(a: Vec2, b: Vec2) -> Vec2 {
     vec2(a.x * b.x, a.y * b.y)
 }
@@ -504,7 +4127,18 @@ fn scale_color(base_color: Color32, color_factor: f32) -> Color32 {
         (base_color.r() as f32 * color_factor) as u8,
         (base_color.g() as f32 * color_factor) as u8,
         (base_color.b() as f32 * color_factor) as u8,
-        (base_color.a()) as u8,
+        base_color.a(),
+    )
+}
+
+fn lerp_color(from: Color32, to: Color32, t: f32) -> Color32 {
+    let t = t.clamp(0., 1.);
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color32::from_rgba_unmultiplied(
+        lerp(from.r(), to.r()),
+        lerp(from.g(), to.g()),
+        lerp(from.b(), to.b()),
+        lerp(from.a(), to.a()),
     )
 }
 
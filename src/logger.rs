@@ -0,0 +1,69 @@
+use crate::{ToastLevel, Toasts};
+use crossbeam_channel::Sender;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// A [`Log`] implementation forwarding records to a [`Toasts`] instance.
+/// Created with [`Toasts::logger`], records are turned into toasts on the next [`Toasts::show`].
+/// ```
+/// use egui_notify::Toasts;
+/// use log::{Level, Log, Record};
+///
+/// # egui_notify::__run_test_ctx(|ctx| {
+/// let mut t = Toasts::default();
+/// let logger = t.logger(log::LevelFilter::Warn);
+/// logger.log(&Record::builder().level(Level::Error).args(format_args!("Disk full")).build());
+/// logger.log(&Record::builder().level(Level::Info).args(format_args!("Ignored")).build());
+/// t.show(ctx);
+/// assert_eq!(t.len(), 1);
+/// # });
+/// ```
+pub struct ToastLogger {
+    sender: Sender<(ToastLevel, String)>,
+    level: LevelFilter,
+}
+
+impl ToastLogger {
+    /// Installs the logger as the global [`log`] logger.
+    pub fn init(self) -> Result<(), log::SetLoggerError> {
+        let level = self.level;
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(level);
+        Ok(())
+    }
+}
+
+impl Log for ToastLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            _ = self
+                .sender
+                .send((level_of(record.level()), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn level_of(level: Level) -> ToastLevel {
+    match level {
+        Level::Error => ToastLevel::Error,
+        Level::Warn => ToastLevel::Warning,
+        Level::Info => ToastLevel::Info,
+        Level::Debug => ToastLevel::Debug,
+        Level::Trace => ToastLevel::Trace,
+    }
+}
+
+impl Toasts {
+    /// Creates a [`ToastLogger`] that shows records at or above `level` as toasts.
+    /// Replaces any logger previously created for this instance.
+    pub fn logger(&mut self, level: LevelFilter) -> ToastLogger {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        self.incoming = Some(receiver);
+        ToastLogger { sender, level }
+    }
+}
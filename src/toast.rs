@@ -1,8 +1,17 @@
-use crate::{ERROR_COLOR, INFO_COLOR, SUCCESS_COLOR, TOAST_HEIGHT, TOAST_WIDTH, WARNING_COLOR};
+use crate::{
+    sanitize_caption, seconds_to_duration, truncate_caption, ToastLayout, DEBUG_COLOR, ERROR_COLOR,
+    INFO_COLOR, SUCCESS_COLOR, TOAST_HEIGHT, TOAST_WIDTH, TRACE_COLOR, WARNING_COLOR,
+};
 use crossbeam_channel::{Receiver, Sender};
-use egui::{vec2, Color32, Vec2};
+use egui::{vec2, Color32, Context, Galley, Id, Pos2, Vec2};
 use std::{
+    any::Any,
     fmt::{Debug, Display},
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
     time::{Duration, SystemTime},
 };
 
@@ -17,6 +26,8 @@ pub enum ToastLevel {
     Warning,
     Error,
     Success,
+    Debug,
+    Trace,
     None,
 }
 
@@ -28,28 +39,133 @@ impl ToastLevel {
             Self::Warning => WARNING_COLOR,
             Self::Error => ERROR_COLOR,
             Self::Success => SUCCESS_COLOR,
+            Self::Debug => DEBUG_COLOR,
+            Self::Trace => TRACE_COLOR,
             Self::None => Color32::GRAY,
         }
     }
-}
 
-impl Display for ToastLevel {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let icon = match self {
+    /// Glyph used as the level's icon, empty for [`ToastLevel::None`].
+    /// Phosphor glyphs are used with the `phosphor` feature, built-in ones otherwise.
+    /// ```
+    /// use egui_notify::ToastLevel;
+    ///
+    /// #[cfg(feature = "phosphor")]
+    /// assert_eq!(ToastLevel::Info.icon(), egui_phosphor::regular::INFO);
+    /// #[cfg(not(feature = "phosphor"))]
+    /// assert_eq!(ToastLevel::Info.icon(), "ℹ");
+    /// assert_eq!(ToastLevel::Error.to_string(), ToastLevel::Error.icon());
+    /// assert_eq!(ToastLevel::None.icon(), "");
+    /// assert_ne!(ToastLevel::Debug.icon(), ToastLevel::Trace.icon());
+    /// ```
+    #[cfg(feature = "phosphor")]
+    pub const fn icon(&self) -> &'static str {
+        match self {
             Self::Info => egui_phosphor::regular::INFO,
             Self::Warning => egui_phosphor::regular::QUESTION,
             Self::Error => egui_phosphor::regular::WARNING_DIAMOND,
             Self::Success => egui_phosphor::regular::CHECK_CIRCLE,
+            Self::Debug => egui_phosphor::regular::BUG,
+            Self::Trace => egui_phosphor::regular::FOOTPRINTS,
             Self::None => "",
-        };
-        write!(f, "{icon}")
+        }
+    }
+
+    /// Glyph used as the level's icon, empty for [`ToastLevel::None`].
+    /// Built-in glyphs render with egui's default fonts, no icon font needed.
+    /// ```
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// egui_notify::load_icon_font(&ctx);
+    /// let mut t = Toasts::default();
+    /// t.warning("Careful");
+    ///
+    /// let output = ctx.run(Default::default(), |ctx| t.show(ctx));
+    /// let icon = output.shapes.iter().find_map(|s| match &s.shape {
+    ///     egui::Shape::Text(t) if t.galley.text() == "⚠" => Some(t.galley.clone()),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(ToastLevel::Warning.icon(), "⚠");
+    /// assert!(icon.is_some_and(|galley| galley.size().x > 0.));
+    /// ```
+    #[cfg(not(feature = "phosphor"))]
+    pub const fn icon(&self) -> &'static str {
+        match self {
+            Self::Info => "ℹ",
+            Self::Warning => "⚠",
+            Self::Error => "！",
+            Self::Success => "✔",
+            Self::Debug => "🐛",
+            Self::Trace => "👣",
+            Self::None => "",
+        }
+    }
+
+    /// Lowercase name of the level.
+    /// ```
+    /// use egui_notify::ToastLevel;
+    ///
+    /// assert_eq!(ToastLevel::Warning.name(), "warning");
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::Success => "success",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+            Self::None => "none",
+        }
     }
 }
 
-#[derive(Debug)]
+impl Display for ToastLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.icon())
+    }
+}
+
+/// Horizontal side of a toast.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum Side {
+    Left,
+    #[default]
+    Right,
+}
+
+/// When the closing cross of a closable toast is shown.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseButtonVisibility {
+    /// The cross is always shown.
+    #[default]
+    Always,
+    /// The cross is only shown while the toast is hovered.
+    OnHover {
+        /// Keep room for the cross while it's hidden, so the toast doesn't resize on hover.
+        reserve_space: bool,
+    },
+}
+
+/// Stage of a toast's lifecycle, see [`Toast::phase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastPhase {
+    /// The toast is waiting to be shown, or playing its appear animation.
+    Appearing,
+    /// The toast is fully shown.
+    Idle,
+    /// The toast is playing its disappear animation.
+    Disappearing,
+    /// The toast is done and removed from the collection on the next show.
+    Removed,
+}
+
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum ToastState {
     Appear,
-    Disapper,
+    Disappear,
     Disappeared,
     Idle,
 }
@@ -59,7 +175,7 @@ impl ToastState {
         matches!(self, Self::Appear)
     }
     pub fn disappearing(&self) -> bool {
-        matches!(self, Self::Disapper)
+        matches!(self, Self::Disappear)
     }
     pub fn disappeared(&self) -> bool {
         matches!(self, Self::Disappeared)
@@ -69,21 +185,199 @@ impl ToastState {
     }
 }
 
+/// Button shown inside a toast, see [`Toast::add_action`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToastAction {
+    /// Text of the button.
+    pub label: String,
+    /// Identifier reported once the button is clicked, see
+    /// [`Toasts::clicked_actions`](crate::Toasts::clicked_actions).
+    pub id: usize,
+}
+
+impl ToastAction {
+    /// Button labelled `label`, reported as `id` when clicked.
+    pub fn new(label: impl Into<String>, id: usize) -> Self {
+        Self {
+            label: label.into(),
+            id,
+        }
+    }
+}
+
 /// Container for options for initlizing toasts
 #[derive(Debug, Clone)]
 pub struct ToastOptions {
-    // (initial, current)
+    /// Duration in seconds as `(initial, current)`, or `None` for no expiry.
     pub duration: Option<(f32, f32)>,
+    /// Level of the toast.
     pub level: ToastLevel,
+    /// Can the user close the toast?
     pub closable: bool,
+    /// Was `closable` set with a builder or setter? Error levels only make toasts unclosable
+    /// when it wasn't.
+    pub closable_set: bool,
+    /// Should a progress bar be shown?
     pub show_progress_bar: bool,
+    /// Padding overriding the one of [`Toasts`](crate::Toasts), if any.
+    pub padding: Option<Vec2>,
+    /// Side the closing cross is painted on.
+    pub close_side: Side,
+    /// Side the level icon is painted on, the caption stays left-to-right.
+    pub icon_side: Side,
+    /// When the closing cross is shown.
+    pub close_visibility: CloseButtonVisibility,
+    /// Should line breaks in the caption be replaced with spaces?
+    pub single_line: bool,
+    /// Width the toast may grow to before its caption wraps.
+    pub max_width: Option<f32>,
+    /// Number of characters the caption is cut down to, the full caption is shown when hovered.
+    pub max_caption_chars: Option<usize>,
+    /// Should adding the toast call the notify hook, e.g. to play a sound?
+    /// See [`Toasts::with_notify_hook`](crate::Toasts::with_notify_hook).
+    pub notify: bool,
+    /// Glyph overriding the level icon, if any. Shown even for [`ToastLevel::None`].
+    pub icon: Option<String>,
+    /// Color of the level icon overriding the level color, if any.
+    pub icon_color: Option<Color32>,
+    /// Color of the closing cross overriding the theme's foreground color, if any.
+    pub cross_color: Option<Color32>,
+    /// Color the border shifts toward once the remaining fraction of the duration drops below
+    /// the threshold.
+    pub expiry_warning: Option<(Color32, f32)>,
+    /// Buttons shown to the right of the caption.
+    pub actions: Vec<ToastAction>,
 }
 
 impl ToastOptions {
+    /// In what time should the toast expire?
     pub fn set_duration(&mut self, duration: Duration) {
         let secs = duration_to_seconds_f32(duration);
         self.duration = Some((secs, secs));
     }
+
+    /// Duration in seconds the toast is shown for, or `None` if it doesn't expire.
+    pub fn duration_secs(&self) -> Option<f32> {
+        self.duration.map(|(initial, _)| initial)
+    }
+
+    /// In how many seconds should the toast expire? Resets the remaining time too.
+    /// ```
+    /// use egui_notify::ToastOptions;
+    ///
+    /// let mut options = ToastOptions::default();
+    /// options.set_duration_secs(2.5);
+    /// assert_eq!(options.duration, Some((2.5, 2.5)));
+    /// assert_eq!(options.duration_secs(), Some(2.5));
+    /// ```
+    pub fn set_duration_secs(&mut self, secs: f32) {
+        self.duration = Some((secs, secs));
+    }
+
+    /// Set the level of the toast. Error toasts are made unclosable, unless closable was set
+    /// explicitly, like [`Toast::set_level`] does.
+    /// ```
+    /// use egui_notify::{ToastLevel, ToastOptions, Toasts};
+    ///
+    /// let mut toasts = Toasts::default();
+    /// toasts.default_options = ToastOptions::default()
+    ///     .with_level(ToastLevel::Error)
+    ///     .with_progress_bar(false);
+    /// assert!(!toasts.default_options.closable);
+    ///
+    /// let options = ToastOptions::default()
+    ///     .with_closable(true)
+    ///     .with_level(ToastLevel::Error);
+    /// assert!(options.closable);
+    /// ```
+    pub fn with_level(mut self, level: ToastLevel) -> Self {
+        self.set_level(level);
+        self
+    }
+
+    /// Can the user close the toast?
+    pub fn with_closable(mut self, closable: bool) -> Self {
+        self.set_closable(closable);
+        self
+    }
+
+    fn set_level(&mut self, level: ToastLevel) {
+        self.level = level;
+        if level == ToastLevel::Error && !self.closable_set {
+            self.closable = false;
+        }
+    }
+
+    fn set_closable(&mut self, closable: bool) {
+        self.closable = closable;
+        self.closable_set = true;
+    }
+
+    /// Should a progress bar be shown?
+    pub fn with_progress_bar(mut self, show_progress_bar: bool) -> Self {
+        self.show_progress_bar = show_progress_bar;
+        self
+    }
+
+    /// Should adding the toast call the notify hook?
+    pub fn with_notify(mut self, notify: bool) -> Self {
+        self.notify = notify;
+        self
+    }
+
+    /// Glyph shown instead of the level icon.
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Color of the level icon, instead of the level color.
+    /// ```
+    /// use egui::Color32;
+    /// use egui_notify::{ToastLevel, ToastOptions, Toasts};
+    ///
+    /// let mut t = Toasts::default().with_default_options(
+    ///     ToastOptions::default()
+    ///         .with_icon_color(Color32::RED)
+    ///         .with_cross_color(Color32::BLUE),
+    /// );
+    /// t.info("Hello");
+    ///
+    /// let output = egui::Context::default().run(Default::default(), |ctx| t.show(ctx));
+    /// let color_of = |text: &str| {
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if t.galley.text() == text => {
+    ///             Some(t.galley.job.sections[0].format.color)
+    ///         }
+    ///         _ => None,
+    ///     })
+    /// };
+    /// assert_eq!(color_of(ToastLevel::Info.icon()), Some(Color32::RED));
+    /// assert_eq!(color_of("❌"), Some(Color32::BLUE));
+    /// ```
+    pub fn with_icon_color(mut self, color: Color32) -> Self {
+        self.icon_color = Some(color);
+        self
+    }
+
+    /// Color of the closing cross, instead of the theme's foreground color.
+    pub fn with_cross_color(mut self, color: Color32) -> Self {
+        self.cross_color = Some(color);
+        self
+    }
+
+    /// Shift the border toward `color` once less than `threshold` (`0.0..=1.0`) of the
+    /// duration remains.
+    pub fn with_expiry_warning_color(mut self, color: Color32, threshold: f32) -> Self {
+        self.expiry_warning = Some((color, threshold));
+        self
+    }
+
+    /// Add a button labelled `label`, reported as `id` when clicked.
+    pub fn with_action(mut self, label: impl Into<String>, id: usize) -> Self {
+        self.actions.push(ToastAction::new(label, id));
+        self
+    }
 }
 
 impl Default for ToastOptions {
@@ -92,39 +386,119 @@ impl Default for ToastOptions {
             duration: Some((DEFAULT_TOAST_DURATION, DEFAULT_TOAST_DURATION)),
             level: ToastLevel::None,
             closable: true,
+            closable_set: false,
             show_progress_bar: true,
+            padding: None,
+            close_side: Side::Right,
+            icon_side: Side::Left,
+            close_visibility: CloseButtonVisibility::Always,
+            single_line: false,
+            max_width: None,
+            max_caption_chars: None,
+            notify: false,
+            icon: None,
+            icon_color: None,
+            cross_color: None,
+            expiry_warning: None,
+            actions: Vec::new(),
         }
     }
 }
 
+/// Update sent to a toast through the channel returned by [`Toast::create_channel`].
 pub struct ToastUpdate {
     pub(crate) caption: Option<String>,
     pub(crate) level: Option<ToastLevel>,
     pub(crate) fallback_options: Option<ToastOptions>,
     pub(crate) use_original_options: bool,
+    pub(crate) progress: Option<f32>,
+    pub(crate) result: Option<(ToastLevel, String, Duration)>,
 }
 
 impl ToastUpdate {
+    /// Update that replaces the caption of the toast.
     pub fn caption(caption: impl Into<String>) -> Self {
         Self {
             use_original_options: false,
             caption: Some(caption.into()),
             fallback_options: None,
             level: None,
+            progress: None,
+            result: None,
+        }
+    }
+    /// Update that shows the fraction of the task done, from `0.0` to `1.0`, as a progress bar.
+    pub fn progress(progress: f32) -> Self {
+        Self {
+            use_original_options: false,
+            caption: None,
+            fallback_options: None,
+            level: None,
+            progress: Some(progress),
+            result: None,
         }
     }
+    /// Final update turning the toast into a `level` toast expiring after `duration`.
+    /// The toast stops listening to the channel afterwards.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::{Toast, ToastLevel, ToastUpdate, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut frame = |t: &mut Toasts| {
+    ///     let input = egui::RawInput { predicted_dt: 0.1, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    /// };
+    ///
+    /// let mut t = Toasts::default();
+    /// let mut toast = Toast::basic("Exporting");
+    /// let sender = toast.create_channel();
+    /// t.add(toast);
+    ///
+    /// sender.send(ToastUpdate::progress(0.5)).unwrap();
+    /// frame(&mut t);
+    /// assert_eq!(t.iter().next().unwrap().task_progress(), Some(0.5));
+    ///
+    /// let done = ToastUpdate::result(ToastLevel::Success, "Exported", Duration::from_secs(1));
+    /// sender.send(done).unwrap();
+    /// frame(&mut t);
+    /// let toast = t.iter().next().unwrap();
+    /// assert_eq!((toast.level(), toast.caption()), (ToastLevel::Success, "Exported"));
+    /// assert_eq!(toast.task_progress(), None);
+    ///
+    /// for _ in 0..20 {
+    ///     frame(&mut t);
+    /// }
+    /// assert!(t.is_empty());
+    /// ```
+    pub fn result(level: ToastLevel, caption: impl Into<String>, duration: Duration) -> Self {
+        Self {
+            use_original_options: false,
+            caption: None,
+            fallback_options: None,
+            level: None,
+            progress: None,
+            result: Some((level, caption.into(), duration)),
+        }
+    }
+    /// Shortcut for a caption update with success `level`.
     pub fn success(caption: impl Into<String>) -> Self {
         Self::caption(caption).with_level(ToastLevel::Success)
     }
+    /// Shortcut for a caption update with error `level`.
     pub fn error(caption: impl Into<String>) -> Self {
         Self::caption(caption).with_level(ToastLevel::Error)
     }
+    /// Shortcut for a caption update with warning `level`.
     pub fn warning(caption: impl Into<String>) -> Self {
         Self::caption(caption).with_level(ToastLevel::Warning)
     }
+    /// Shortcut for a caption update with info `level`.
     pub fn info(caption: impl Into<String>) -> Self {
         Self::caption(caption).with_level(ToastLevel::Info)
     }
+    /// Change the level of the toast.
     pub fn with_level(mut self, level: ToastLevel) -> Self {
         self.level = Some(level);
         if let Some(fallback_options) = self.fallback_options.as_mut() {
@@ -132,10 +506,12 @@ impl ToastUpdate {
         }
         self
     }
+    /// Restore the options the toast was created with once the channel is closed.
     pub fn with_original_options(mut self) -> Self {
         self.use_original_options = true;
         self
     }
+    /// Options to apply to the toast once the channel is closed.
     pub fn with_fallback_options(mut self, mut fallback_options: ToastOptions) -> Self {
         if let Some(level) = self.level {
             fallback_options.level = level;
@@ -145,10 +521,116 @@ impl ToastUpdate {
     }
 }
 
+/// Handle to a toast created with [`Toasts::loading`](crate::Toasts::loading).
+///
+/// The toast shows an indeterminate progress bar until it is finished with
+/// [`LoadingToast::finish_success`] or [`LoadingToast::finish_error`], after which
+/// the default options (including duration) of the [`Toasts`](crate::Toasts) are restored.
+/// Dropping the handle without finishing it dismisses the toast.
+/// ```
+/// use egui_notify::{ToastPhase, Toasts};
+///
+/// let mut t = Toasts::default();
+/// let first = t.loading("Downloading");
+/// let second = t.loading("Uploading");
+/// drop((first, second));
+/// egui_notify::__run_test_ctx(|ctx| t.show(ctx));
+/// assert!(t.iter().all(|toast| toast.phase() == ToastPhase::Disappearing));
+/// ```
+pub struct LoadingToast {
+    sender: Sender<ToastUpdate>,
+    options: ToastOptions,
+}
+
+impl LoadingToast {
+    pub(crate) fn new(sender: Sender<ToastUpdate>, mut options: ToastOptions) -> Self {
+        if let Some((initial, current)) = options.duration.as_mut() {
+            *current = *initial;
+        }
+        Self { sender, options }
+    }
+
+    /// Replaces the caption while the toast is still loading.
+    pub fn set_caption(&self, caption: impl Into<String>) {
+        _ = self.sender.send(ToastUpdate::caption(caption));
+    }
+
+    /// Turns the toast into a success toast.
+    pub fn finish_success(self, caption: impl Into<String>) {
+        self.finish(ToastUpdate::success(caption));
+    }
+
+    /// Turns the toast into an error toast.
+    pub fn finish_error(self, caption: impl Into<String>) {
+        self.finish(ToastUpdate::error(caption));
+    }
+
+    fn finish(self, update: ToastUpdate) {
+        _ = self.sender.send(update.with_fallback_options(self.options));
+    }
+}
+
+/// Type-erased user data attached to a toast.
+pub(crate) struct ToastData(Box<dyn Any + Send>);
+
+impl Debug for ToastData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToastData").finish_non_exhaustive()
+    }
+}
+
+/// Position a toast follows, queried every frame.
+#[derive(Clone)]
+pub(crate) struct Follow(pub(crate) Arc<dyn Fn() -> Pos2 + Send + Sync>);
+
+impl Debug for Follow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Follow").finish_non_exhaustive()
+    }
+}
+
+/// Caption a toast is bound to, evaluated every frame.
+#[derive(Clone)]
+pub(crate) struct CaptionBinding(pub(crate) Arc<dyn Fn() -> String + Send + Sync>);
+
+impl Debug for CaptionBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CaptionBinding").finish_non_exhaustive()
+    }
+}
+
+/// Condition dismissing a toast once it returns `true`, checked every frame.
+pub(crate) struct DismissCondition(pub(crate) Box<dyn Fn() -> bool + Send>);
+
+impl Debug for DismissCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DismissCondition").finish_non_exhaustive()
+    }
+}
+
+/// Callback run when the body of a toast is clicked.
+pub(crate) struct ClickCallback(pub(crate) Box<dyn FnMut() + Send>);
+
+impl Debug for ClickCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClickCallback").finish_non_exhaustive()
+    }
+}
+
+/// Callback run once a toast is done disappearing.
+pub(crate) struct DisappearCallback(pub(crate) Box<dyn FnOnce(&Toast) + Send>);
+
+impl Debug for DisappearCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DisappearCallback").finish_non_exhaustive()
+    }
+}
+
 /// Single notification or *toast*
 #[derive(Debug)]
 pub struct Toast {
     pub(crate) caption: String,
+    pub(crate) galley: Option<Arc<Galley>>,
     pub(crate) options: ToastOptions,
     pub(crate) original_options: ToastOptions,
     pub(crate) fallback_options: Option<ToastOptions>,
@@ -158,13 +640,110 @@ pub struct Toast {
 
     pub(crate) toast_hovered: bool,
     pub(crate) cross_hovered: bool,
+    /// Index of the hovered action button, if any.
+    pub(crate) action_hovered: Option<usize>,
 
     pub(crate) timestamp: u128,
     pub(crate) add_index: usize,
+    pub(crate) id: Option<Id>,
     pub(crate) update_reciever: Option<Receiver<ToastUpdate>>,
+    pub(crate) loading: bool,
+    pub(crate) task_progress: Option<f32>,
+    pub(crate) data: Option<ToastData>,
+    pub(crate) follow: Option<Follow>,
+    pub(crate) caption_binding: Option<CaptionBinding>,
+    pub(crate) dismiss_condition: Option<DismissCondition>,
+    pub(crate) pinned_window: Option<Id>,
+    pub(crate) on_disappeared: Option<DisappearCallback>,
+    pub(crate) on_click: Option<ClickCallback>,
 
     pub(crate) state: ToastState,
+    pub(crate) start_delay: Option<f32>,
+    pub(crate) dismiss_delay: Option<f32>,
     pub(crate) value: f32,
+    pub(crate) progress: f32,
+    /// Seconds the toast has been shown for, not counting its start delay.
+    pub(crate) shown_for: f32,
+}
+
+/// Clones the toast for use as a template. The update channel, the attached data, the
+/// dismiss condition and the disappear and click callbacks aren't cloned, so a cloned
+/// loading toast stops loading. The clone gets its own [timestamp](Toast::timestamp).
+/// ```
+/// use egui_notify::{Toast, Toasts};
+///
+/// let mut template = Toast::basic("Uploading").info();
+/// let _sender = template.create_channel();
+/// let copy = template.clone();
+/// assert_eq!(copy.caption(), template.caption());
+/// assert_eq!(copy.level(), template.level());
+/// assert_ne!(copy.timestamp(), template.timestamp());
+///
+/// let mut t = Toasts::default();
+/// t.add(template);
+/// t.add(copy);
+/// egui_notify::__run_test_ctx(|ctx| t.show(ctx));
+/// assert_eq!(t.debug_stats().pending, 1);
+/// ```
+impl Clone for Toast {
+    fn clone(&self) -> Self {
+        Self {
+            caption: self.caption.clone(),
+            galley: self.galley.clone(),
+            options: self.options.clone(),
+            original_options: self.original_options.clone(),
+            fallback_options: self.fallback_options.clone(),
+            height: self.height,
+            width: self.width,
+            toast_hovered: self.toast_hovered,
+            cross_hovered: self.cross_hovered,
+            action_hovered: self.action_hovered,
+            timestamp: unique_timestamp(),
+            add_index: self.add_index,
+            id: self.id,
+            update_reciever: None,
+            loading: false,
+            task_progress: None,
+            data: None,
+            follow: self.follow.clone(),
+            caption_binding: self.caption_binding.clone(),
+            dismiss_condition: None,
+            pinned_window: self.pinned_window,
+            on_disappeared: None,
+            on_click: None,
+            state: self.state,
+            start_delay: self.start_delay,
+            dismiss_delay: self.dismiss_delay,
+            value: self.value,
+            progress: self.progress,
+            shown_for: self.shown_for,
+        }
+    }
+}
+
+/// Moves `value` toward `1.0` by `dt` over an animation lasting `duration` seconds.
+fn step_value(value: f32, dt: f32, duration: f32) -> f32 {
+    if duration > 0. {
+        (value + dt / duration).min(1.)
+    } else {
+        1.
+    }
+}
+
+/// Milliseconds since the Unix epoch, moved past the last one handed out if needed so
+/// that every toast gets its own.
+fn unique_timestamp() -> u128 {
+    static LAST: AtomicU64 = AtomicU64::new(0);
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    let last = LAST
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| {
+            Some(now.max(last + 1))
+        })
+        .unwrap_or_default();
+    now.max(last + 1).into()
 }
 
 fn duration_to_seconds_f32(duration: Duration) -> f32 {
@@ -173,24 +752,37 @@ fn duration_to_seconds_f32(duration: Duration) -> f32 {
 
 impl Toast {
     fn new(caption: impl Into<String>, options: ToastOptions) -> Self {
-        let timestamp = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis();
+        let timestamp = unique_timestamp();
         Self {
             caption: caption.into(),
+            galley: None,
             height: TOAST_HEIGHT,
             width: TOAST_WIDTH,
             original_options: options.clone(),
             options,
             toast_hovered: false,
             cross_hovered: false,
+            action_hovered: None,
             update_reciever: None,
+            loading: false,
+            task_progress: None,
+            data: None,
+            follow: None,
+            caption_binding: None,
+            dismiss_condition: None,
+            pinned_window: None,
+            on_disappeared: None,
+            on_click: None,
             timestamp,
             add_index: 0,
+            id: None,
             value: 0.,
+            progress: 1.,
+            shown_for: 0.,
             fallback_options: None,
             state: ToastState::Appear,
+            start_delay: None,
+            dismiss_delay: None,
         }
     }
 
@@ -208,6 +800,23 @@ impl Toast {
         Self::new(caption, ToastOptions::default())
     }
 
+    /// Creates new toast showing only the icon of `level`, e.g. for brief status blips.
+    /// It can't be closed, as the cross would be as large as the icon.
+    /// ```
+    /// use egui_notify::{Toast, ToastLevel};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let padding = egui::vec2(10., 10.);
+    /// let _ = ctx.run(Default::default(), |ctx| {
+    ///     let icon = Toast::icon_only(ToastLevel::Success).measure(ctx, padding) - padding * 2.;
+    ///     assert!(icon.min_elem() > 0.);
+    ///     assert!((icon.x - icon.y).abs() < 4.);
+    /// });
+    /// ```
+    pub fn icon_only(level: ToastLevel) -> Self {
+        Self::basic("").with_level(level).with_closable(false)
+    }
+
     /// Creates new success toast, can be closed by default.
     pub fn success(mut self) -> Self {
         self.options.level = ToastLevel::Success;
@@ -226,9 +835,34 @@ impl Toast {
         self
     }
 
+    /// Creates new debug toast, can be closed by default.
+    pub fn debug(mut self) -> Self {
+        self.options.level = ToastLevel::Debug;
+        self
+    }
+
+    /// Creates new trace toast, can be closed by default.
+    pub fn trace(mut self) -> Self {
+        self.options.level = ToastLevel::Trace;
+        self
+    }
+
     /// Creates new error toast, can not be closed by default.
+    /// ```
+    /// use egui_notify::Toast;
+    ///
+    /// assert!(!Toast::basic("Failed").error().closable());
+    /// assert!(Toast::basic("Failed").error().with_closable(true).closable());
+    /// assert!(Toast::basic("Failed").with_closable(true).error().closable());
+    /// ```
     pub fn error(mut self) -> Self {
-        self.options.level = ToastLevel::Error;
+        self.set_level(ToastLevel::Error);
+        self
+    }
+
+    /// Change the level of the toast, see [`Toast::set_level`].
+    pub fn with_level(mut self, level: ToastLevel) -> Self {
+        self.set_level(level);
         self
     }
 
@@ -238,21 +872,606 @@ impl Toast {
         self
     }
 
+    /// Replace all of the toast's options, e.g. on the toast returned by
+    /// [`Toasts::add`](crate::Toasts::add). Unlike [`Toast::with_options`], this keeps the
+    /// toast's position in its animation, and the new options take effect on the next
+    /// [`Toasts::show`](crate::Toasts::show).
+    /// ```
+    /// use egui_notify::{Toast, ToastLevel, ToastOptions, Toasts};
+    /// use std::time::Duration;
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut frame = |t: &mut Toasts| {
+    ///     let input = egui::RawInput { predicted_dt: 0.1, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    /// };
+    ///
+    /// let mut t = Toasts::default();
+    /// t.info("Saving").set_duration(None);
+    /// frame(&mut t);
+    ///
+    /// let mut options = ToastOptions::default();
+    /// options.level = ToastLevel::Success;
+    /// options.set_duration(Duration::from_millis(500));
+    /// t.iter_mut().next().unwrap().replace_options(options);
+    /// for _ in 0..15 {
+    ///     frame(&mut t);
+    /// }
+    /// assert!(t.is_empty());
+    /// ```
+    pub fn replace_options(&mut self, options: ToastOptions) -> &mut Self {
+        self.options = options;
+        self
+    }
+
+    /// Paint a prebuilt galley instead of laying out the caption.
+    /// The toast is sized from the galley's rect.
+    /// ```
+    /// use egui::{Color32, FontId};
+    /// use egui_notify::{Toast, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut shown = |text: &str| {
+    ///     let mut t = Toasts::default().suppress_initial_animation(true);
+    ///     let (mut toast_rect, mut galley) = (egui::Rect::NOTHING, None);
+    ///     let output = ctx.run(Default::default(), |ctx| {
+    ///         let font = FontId::monospace(20.);
+    ///         let built = ctx.fonts(|f| f.layout_no_wrap(text.into(), font, Color32::WHITE));
+    ///         t.add(Toast::basic("").with_galley(built.clone()));
+    ///         galley = Some(built);
+    ///         toast_rect = t.show_and_interact(ctx)[0].1.rect;
+    ///     });
+    ///     let galley = galley.unwrap();
+    ///     let painted = output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if std::sync::Arc::ptr_eq(&t.galley, &galley) => {
+    ///             Some(t.galley.rect.translate(t.pos.to_vec2()))
+    ///         }
+    ///         _ => None,
+    ///     });
+    ///     assert!(toast_rect.contains_rect(painted.unwrap()));
+    ///     (toast_rect.width(), galley.rect.width())
+    /// };
+    ///
+    /// let (short_toast, short_galley) = shown("Hi");
+    /// let (long_toast, long_galley) = shown("Hello, World!");
+    /// assert!((long_toast - short_toast - (long_galley - short_galley)).abs() < 1e-3);
+    /// ```
+    pub fn with_galley(mut self, galley: Arc<Galley>) -> Self {
+        self.galley = Some(galley);
+        self
+    }
+
+    /// Give the toast a stable identifier. Adding a toast with the same identifier as a
+    /// toast that is still shown updates that toast in place instead of adding a new one.
+    /// ```
+    /// use egui_notify::{Toast, ToastPhase, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.add(Toast::basic("Syncing 1/2").with_id("sync"));
+    /// let _ = ctx.run(Default::default(), |ctx| t.show(ctx));
+    /// assert_eq!(t.iter().next().unwrap().phase(), ToastPhase::Idle);
+    ///
+    /// t.add(Toast::basic("Syncing 2/2").with_id("sync"));
+    /// let _ = ctx.run(Default::default(), |ctx| t.show(ctx));
+    /// assert_eq!(t.len(), 1);
+    /// let toast = t.iter().next().unwrap();
+    /// assert_eq!(toast.caption(), "Syncing 2/2");
+    /// assert_eq!(toast.phase(), ToastPhase::Idle);
+    /// ```
+    pub fn with_id(mut self, id: impl Hash) -> Self {
+        self.id = Some(Id::new(id));
+        self
+    }
+
+    /// Keep the toast hidden for `delay` after it is added, then let it appear.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::{Toast, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default();
+    /// t.add(Toast::basic("Later").with_delay(Duration::from_secs(1)));
+    /// let mut painted = |t: &mut Toasts, time: f64| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| t.show(ctx));
+    ///     output.shapes.iter().any(|s| {
+    ///         matches!(&s.shape, egui::Shape::Text(t) if t.galley.text() == "Later")
+    ///     })
+    /// };
+    ///
+    /// assert!(!painted(&mut t, 0.));
+    /// assert!(!painted(&mut t, 0.5));
+    /// assert!(!painted(&mut t, 0.9));
+    /// painted(&mut t, 1.1);
+    /// assert!(painted(&mut t, 1.5));
+    /// ```
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.start_delay = Some(duration_to_seconds_f32(delay));
+        self
+    }
+
+    /// Show the toast centered above the position returned by `follow` each frame,
+    /// e.g. to point at the widget that triggered it, instead of in the stack.
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use egui::pos2;
+    /// use egui_notify::{Toast, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let target = Arc::new(Mutex::new(pos2(200., 300.)));
+    /// let follow = target.clone();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Stacked");
+    /// t.add(Toast::basic("Copied").with_follow(move || *follow.lock().unwrap()));
+    /// let mut rect = |t: &mut Toasts| {
+    ///     let mut rect = egui::Rect::NOTHING;
+    ///     let _ = ctx.run(Default::default(), |ctx| {
+    ///         rect = t.show_and_interact(ctx).into_iter().find(|(i, _)| *i == 1).unwrap().1.rect;
+    ///     });
+    ///     rect
+    /// };
+    ///
+    /// assert_eq!(rect(&mut t).center_bottom(), pos2(200., 300.));
+    /// *target.lock().unwrap() = pos2(400., 100.);
+    /// assert_eq!(rect(&mut t).center_bottom(), pos2(400., 100.));
+    /// ```
+    pub fn with_follow(mut self, follow: impl Fn() -> Pos2 + Send + Sync + 'static) -> Self {
+        self.follow = Some(Follow(Arc::new(follow)));
+        self
+    }
+
+    /// Bind the caption to `caption`, which is called every frame to refresh it,
+    /// e.g. for a counter updated by the UI thread.
+    /// ```
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut frame = |t: &mut Toasts| {
+    ///     let output = ctx.run(Default::default(), |ctx| t.show(ctx));
+    ///     output.shapes.iter().any(|s| match &s.shape {
+    ///         egui::Shape::Text(text) => text.galley.text() == "Uploading 3/10",
+    ///         _ => false,
+    ///     })
+    /// };
+    ///
+    /// let uploaded = Arc::new(AtomicUsize::new(2));
+    /// let count = uploaded.clone();
+    /// let mut t = Toasts::default();
+    /// t.info("").set_duration(None).bind_caption(move || {
+    ///     format!("Uploading {}/10", count.load(Ordering::Relaxed))
+    /// });
+    /// assert!(!frame(&mut t));
+    ///
+    /// uploaded.store(3, Ordering::Relaxed);
+    /// assert!(frame(&mut t));
+    /// assert_eq!(t.iter().next().unwrap().caption(), "Uploading 3/10");
+    /// ```
+    pub fn bind_caption(
+        &mut self,
+        caption: impl Fn() -> String + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.caption_binding = Some(CaptionBinding(Arc::new(caption)));
+        self
+    }
+
     /// Used for creating a unique identifier for this toast.
     pub fn with_add_index(mut self, add_index: usize) -> Self {
         self.add_index = add_index;
         self
     }
 
-    /// Change the level of the toast
+    /// Change the level of the toast. Error toasts are made unclosable, unless closable was
+    /// set explicitly with [`Toast::set_closable`] before or after.
+    /// ```
+    /// use egui_notify::{Toast, ToastLevel};
+    ///
+    /// let mut toast = Toast::basic("Failed");
+    /// assert!(!toast.set_level(ToastLevel::Error).closable());
+    ///
+    /// let mut toast = Toast::basic("Failed");
+    /// toast.set_closable(true).set_level(ToastLevel::Error);
+    /// assert!(toast.closable());
+    /// ```
     pub fn set_level(&mut self, level: ToastLevel) -> &mut Self {
-        self.options.level = level;
+        self.options.set_level(level);
+        self
+    }
+
+    /// Should adding the toast call the notify hook, e.g. to play a sound?
+    pub fn with_notify(mut self, notify: bool) -> Self {
+        self.options.notify = notify;
+        self
+    }
+
+    /// Can the user close the toast?
+    pub fn with_closable(mut self, closable: bool) -> Self {
+        self.options.set_closable(closable);
+        self
+    }
+
+    /// Caption of the toast.
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    /// Caption as it is shown, with line breaks and tabs normalized and cut down to
+    /// [`ToastOptions::max_caption_chars`] characters.
+    /// ```
+    /// use egui_notify::Toast;
+    ///
+    /// let mut toast = Toast::basic("Grüße 👨‍👩‍👧 from Zürich");
+    /// toast.set_max_caption_chars(Some(7));
+    /// assert_eq!(toast.displayed_caption(), "Grüße 👨‍👩‍👧…");
+    /// toast.set_max_caption_chars(Some(64));
+    /// assert_eq!(toast.displayed_caption(), toast.caption());
+    /// ```
+    /// Mixed line endings all count as one line break, for the layout as well:
+    /// ```
+    /// use egui_notify::{Toast, Toasts};
+    ///
+    /// let mixed = "One\r\nTwo\rThree\nFour";
+    /// assert_eq!(Toast::basic(mixed).displayed_caption().lines().count(), 4);
+    ///
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info(mixed);
+    /// t.info("One\nTwo\nThree\nFour");
+    /// let mut sizes = vec![];
+    /// let output = egui::Context::default().run(Default::default(), |ctx| {
+    ///     sizes = t.show_and_interact(ctx).iter().map(|(_, r)| r.rect.size()).collect();
+    /// });
+    /// assert_eq!(sizes[0], sizes[1]);
+    /// let rows = output.shapes.iter().find_map(|s| match &s.shape {
+    ///     egui::Shape::Text(t) if t.galley.text().starts_with("One") => Some(t.galley.rows.len()),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(rows, Some(4));
+    /// ```
+    pub fn displayed_caption(&self) -> String {
+        let caption = sanitize_caption(&self.caption, self.options.single_line);
+        truncate_caption(&caption, self.options.max_caption_chars).unwrap_or(caption)
+    }
+
+    /// Whether the caption is cut down to [`ToastOptions::max_caption_chars`].
+    pub(crate) fn caption_truncated(&self) -> bool {
+        let caption = sanitize_caption(&self.caption, self.options.single_line);
+        truncate_caption(&caption, self.options.max_caption_chars).is_some()
+    }
+
+    /// Level of the toast.
+    pub const fn level(&self) -> ToastLevel {
+        self.options.level
+    }
+
+    /// Can the user close the toast?
+    pub const fn closable(&self) -> bool {
+        self.options.closable
+    }
+
+    /// Stage of the toast's lifecycle.
+    /// ```
+    /// use egui_notify::{ToastPhase, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut t = Toasts::default().with_collect_dismissed(true);
+    /// let mut frames = |t: &mut Toasts, n| {
+    ///     for _ in 0..n {
+    ///         let input = egui::RawInput { predicted_dt: 0.1, ..Default::default() };
+    ///         let _ = ctx.run(input, |ctx| t.show(ctx));
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(t.info("Hello").phase(), ToastPhase::Appearing);
+    /// frames(&mut t, 5);
+    /// assert_eq!(t.iter().next().unwrap().phase(), ToastPhase::Idle);
+    /// t.dismiss_all_toasts();
+    /// assert_eq!(t.iter().next().unwrap().phase(), ToastPhase::Disappearing);
+    /// frames(&mut t, 5);
+    /// assert_eq!(t.drain_dismissed()[0].phase(), ToastPhase::Removed);
+    /// ```
+    pub const fn phase(&self) -> ToastPhase {
+        match self.state {
+            ToastState::Appear => ToastPhase::Appearing,
+            ToastState::Idle => ToastPhase::Idle,
+            ToastState::Disappear => ToastPhase::Disappearing,
+            ToastState::Disappeared => ToastPhase::Removed,
+        }
+    }
+
+    /// Was the toast hovered during the last [`Toasts::show`](crate::Toasts::show) call?
+    /// When toasts overlap, only the topmost one under the pointer is hovered.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true).with_spacing(-20.);
+    /// t.info("Below");
+    /// t.info("On top");
+    /// let mut frame = |t: &mut Toasts, event: egui::Event| {
+    ///     let input = egui::RawInput { events: vec![event], ..Default::default() };
+    ///     let mut rects = vec![];
+    ///     let _ = ctx.run(input, |ctx| rects = t.show_and_interact(ctx));
+    ///     rects.into_iter().map(|(_, r)| r.rect).collect::<Vec<_>>()
+    /// };
+    ///
+    /// let rects = frame(&mut t, egui::Event::PointerGone);
+    /// let overlap = rects[0].intersect(rects[1]);
+    /// assert!(overlap.is_positive());
+    /// frame(&mut t, egui::Event::PointerMoved(overlap.center()));
+    /// let hovered: Vec<_> = t.iter().map(|toast| toast.hovered()).collect();
+    /// assert_eq!(hovered, [false, true]);
+    /// ```
+    pub const fn hovered(&self) -> bool {
+        self.toast_hovered
+    }
+
+    /// Milliseconds since the Unix epoch when the toast was created, identifying it in
+    /// [`Toasts::clicked_actions`](crate::Toasts::clicked_actions). Toasts created within the
+    /// same millisecond are a millisecond apart, so no two toasts share a timestamp.
+    /// ```
+    /// use egui_notify::Toast;
+    ///
+    /// let first = Toast::basic("First");
+    /// let second = Toast::basic("Second");
+    /// assert!(first.timestamp() < second.timestamp());
+    /// ```
+    pub const fn timestamp(&self) -> u128 {
+        self.timestamp
+    }
+
+    /// Time left before the toast expires, or `None` if it doesn't expire.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::Toast;
+    ///
+    /// let mut toast = Toast::basic("Hello");
+    /// toast.set_duration(Some(Duration::from_secs(2)));
+    /// assert_eq!(toast.remaining(), Some(Duration::from_secs(2)));
+    /// toast.set_duration(Some(Duration::MAX));
+    /// assert_eq!(toast.remaining(), Some(Duration::MAX));
+    /// ```
+    pub fn remaining(&self) -> Option<Duration> {
+        self.options
+            .duration
+            .map(|(_, current)| seconds_to_duration(current))
+    }
+
+    /// Fraction of the task done, as last sent with [`ToastUpdate::progress`].
+    pub const fn task_progress(&self) -> Option<f32> {
+        self.task_progress
+    }
+
+    /// Add a button labelled `label` to the right of the caption, e.g. "Undo" or "Retry".
+    /// Clicks are reported as `id` by [`Toasts::clicked_actions`](crate::Toasts::clicked_actions).
+    pub fn add_action(&mut self, label: impl Into<String>, id: usize) -> &mut Self {
+        self.options.actions.push(ToastAction::new(label, id));
         self
     }
 
     /// Can use close the toast?
     pub fn set_closable(&mut self, closable: bool) -> &mut Self {
-        self.options.closable = closable;
+        self.options.set_closable(closable);
+        self
+    }
+
+    /// When should the closing cross be shown?
+    /// ```
+    /// use egui::Event;
+    /// use egui_notify::{CloseButtonVisibility, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Hello")
+    ///     .set_close_visibility(CloseButtonVisibility::OnHover { reserve_space: false });
+    /// let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let mut rect = egui::Rect::NOTHING;
+    ///     let output = ctx.run(input, |ctx| rect = t.show_and_interact(ctx)[0].1.rect);
+    ///     let cross = output.shapes.iter().any(|s| {
+    ///         matches!(&s.shape, egui::Shape::Text(t) if t.galley.text() == "❌")
+    ///     });
+    ///     (rect, cross)
+    /// };
+    ///
+    /// let (toast, cross) = frame(&mut t, vec![]);
+    /// assert!(!cross);
+    /// // Hovering is picked up on one frame and laid out on the next
+    /// frame(&mut t, vec![Event::PointerMoved(toast.center())]);
+    /// let (_, cross) = frame(&mut t, vec![]);
+    /// assert!(cross);
+    /// frame(&mut t, vec![Event::PointerGone]);
+    /// let (_, cross) = frame(&mut t, vec![]);
+    /// assert!(!cross);
+    /// ```
+    pub fn set_close_visibility(&mut self, close_visibility: CloseButtonVisibility) -> &mut Self {
+        self.options.close_visibility = close_visibility;
+        self
+    }
+
+    /// Which side should the closing cross be painted on?
+    /// ```
+    /// use egui_notify::{Side, Toasts};
+    ///
+    /// let shown = |side| {
+    ///     let mut t = Toasts::default()
+    ///         .suppress_initial_animation(true)
+    ///         .with_padding(egui::vec2(10., 10.));
+    ///     t.basic("Hello").set_close_side(side);
+    ///     let mut toast = egui::Rect::NOTHING;
+    ///     let output = egui::Context::default().run(Default::default(), |ctx| {
+    ///         toast = t.show_and_interact(ctx)[0].1.rect;
+    ///     });
+    ///     let text_rect = |text: &str| {
+    ///         output.shapes.iter().find_map(|s| match &s.shape {
+    ///             egui::Shape::Text(t) if t.galley.text() == text => {
+    ///                 Some(t.galley.rect.translate(t.pos.to_vec2()))
+    ///             }
+    ///             _ => None,
+    ///         }).unwrap()
+    ///     };
+    ///     (toast, text_rect("❌"), text_rect("Hello"))
+    /// };
+    ///
+    /// let (toast, cross, caption) = shown(Side::Left);
+    /// assert_eq!(cross.min.x, toast.min.x + 10.);
+    /// assert!(cross.max.x < caption.min.x);
+    /// let (right_toast, right_cross, right_caption) = shown(Side::Right);
+    /// assert_eq!(right_cross.max.x, right_toast.max.x - 10.);
+    /// assert!(caption.min.x - toast.min.x > right_caption.min.x - right_toast.min.x);
+    /// ```
+    pub fn set_close_side(&mut self, close_side: Side) -> &mut Self {
+        self.options.close_side = close_side;
+        self
+    }
+
+    /// Which side should the level icon be painted on? The cross stays at the outer edge.
+    /// ```
+    /// use egui_notify::{Side, Toast, ToastLevel};
+    ///
+    /// let icon_x = |side| {
+    ///     let mut toast = Toast::basic("Saved").with_level(ToastLevel::Success);
+    ///     toast.set_icon_side(side);
+    ///     let output = egui::Context::default().run(Default::default(), |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| ui.add(&toast));
+    ///     });
+    ///     let mut texts = output.shapes.iter().filter_map(|s| match &s.shape {
+    ///         egui::Shape::Text(text) => Some((text.galley.text().to_owned(), text.pos.x)),
+    ///         _ => None,
+    ///     });
+    ///     let icon = texts.find(|(text, _)| text == ToastLevel::Success.icon()).unwrap().1;
+    ///     let caption = texts.find(|(text, _)| text == "Saved").unwrap().1;
+    ///     (icon, caption)
+    /// };
+    ///
+    /// let (left_icon, left_caption) = icon_x(Side::Left);
+    /// let (right_icon, right_caption) = icon_x(Side::Right);
+    /// assert!(left_icon < left_caption && right_caption < right_icon);
+    /// assert!(right_caption < left_caption);
+    /// ```
+    pub fn set_icon_side(&mut self, icon_side: Side) -> &mut Self {
+        self.options.icon_side = icon_side;
+        self
+    }
+
+    /// Glyph shown instead of the level icon, also for toasts without a level.
+    /// The icon keeps the level color unless [`Toast::set_icon_color`] is used.
+    /// ```
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// let mut t = Toasts::default();
+    /// t.info("Downloading").set_icon("⬇");
+    /// t.basic("Plain").set_icon("★");
+    ///
+    /// let output = egui::Context::default().run(Default::default(), |ctx| t.show(ctx));
+    /// let color_of = |text: &str| {
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Text(t) if t.galley.text() == text => {
+    ///             Some(t.galley.job.sections[0].format.color)
+    ///         }
+    ///         _ => None,
+    ///     })
+    /// };
+    /// assert_eq!(color_of("⬇"), Some(ToastLevel::Info.color()));
+    /// assert!(color_of("★").is_some());
+    /// assert_eq!(color_of(ToastLevel::Info.icon()), None);
+    /// ```
+    pub fn set_icon(&mut self, icon: impl Into<String>) -> &mut Self {
+        self.options.icon = Some(icon.into());
+        self
+    }
+
+    /// Color of the level icon, or `None` for the level color. The border keeps the level color.
+    /// ```
+    /// use egui::Color32;
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// let mut t = Toasts::default();
+    /// t.warning("Low battery").set_icon_color(Some(Color32::WHITE));
+    ///
+    /// let output = egui::Context::default().run(Default::default(), |ctx| t.show(ctx));
+    /// let icon_color = output.shapes.iter().find_map(|s| match &s.shape {
+    ///     egui::Shape::Text(t) if t.galley.text() == ToastLevel::Warning.icon() => {
+    ///         Some(t.galley.job.sections[0].format.color)
+    ///     }
+    ///     _ => None,
+    /// });
+    /// let border_color = output.shapes.iter().find_map(|s| match &s.shape {
+    ///     egui::Shape::Rect(rect) => Some(rect.stroke.color),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(icon_color, Some(Color32::WHITE));
+    /// assert_eq!(border_color, Some(ToastLevel::Warning.color()));
+    /// ```
+    pub fn set_icon_color(&mut self, icon_color: Option<Color32>) -> &mut Self {
+        self.options.icon_color = icon_color;
+        self
+    }
+
+    /// Shift the border toward `color` once less than `threshold` (`0.0..=1.0`) of the
+    /// duration remains. Set to `None` to keep the level color.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui::Color32;
+    /// use egui_notify::{ToastLevel, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Hello")
+    ///     .set_duration(Some(Duration::from_secs(1)))
+    ///     .set_expiry_warning_color(Some((Color32::RED, 0.5)));
+    /// let mut border_at = |t: &mut Toasts, time: f64| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| t.show(ctx));
+    ///     output.shapes.iter().find_map(|s| match &s.shape {
+    ///         egui::Shape::Rect(rect) => Some(rect.stroke.color),
+    ///         _ => None,
+    ///     }).unwrap()
+    /// };
+    ///
+    /// border_at(&mut t, 0.);
+    /// assert_eq!(border_at(&mut t, 0.3), ToastLevel::Info.color());
+    /// let warning = border_at(&mut t, 0.8);
+    /// assert_ne!(warning, ToastLevel::Info.color());
+    /// assert!(warning.r() > ToastLevel::Info.color().r());
+    /// ```
+    pub fn set_expiry_warning_color(&mut self, warning: Option<(Color32, f32)>) -> &mut Self {
+        self.options.expiry_warning = warning;
+        self
+    }
+
+    /// Should line breaks in the caption be replaced with spaces?
+    /// ```
+    /// use egui_notify::Toast;
+    ///
+    /// # let _ = egui::Context::default().run(Default::default(), |ctx| {
+    /// let mut toast = Toast::basic("First line\nSecond line");
+    /// let multi_line = toast.measure(ctx, egui::Vec2::ZERO);
+    /// toast.set_single_line(true);
+    /// assert!(toast.measure(ctx, egui::Vec2::ZERO).y < multi_line.y);
+    /// # });
+    /// ```
+    pub fn set_single_line(&mut self, single_line: bool) -> &mut Self {
+        self.options.single_line = single_line;
+        self
+    }
+
+    /// Width the toast may grow to before its caption wraps, `None` to never wrap.
+    /// Prebuilt galleys are never wrapped.
+    pub fn set_max_width(&mut self, max_width: Option<f32>) -> &mut Self {
+        self.options.max_width = max_width;
+        self
+    }
+
+    /// Number of characters the caption is cut down to, `None` to show it in full.
+    pub fn set_max_caption_chars(&mut self, max_caption_chars: Option<usize>) -> &mut Self {
+        self.options.max_caption_chars = max_caption_chars;
         self
     }
 
@@ -262,6 +1481,26 @@ impl Toast {
         self
     }
 
+    /// Padding or distance from the toast's bounding box to inner contents.
+    /// Set to `None` to use the padding of [`Toasts`](crate::Toasts).
+    /// ```
+    /// use egui::vec2;
+    /// use egui_notify::Toasts;
+    ///
+    /// let mut t = Toasts::default().suppress_initial_animation(true).with_padding(vec2(10., 10.));
+    /// t.info("Default");
+    /// t.info("Default").set_padding(Some(vec2(20., 15.)));
+    /// let mut sizes = vec![];
+    /// let _ = egui::Context::default().run(Default::default(), |ctx| {
+    ///     sizes = t.show_and_interact(ctx).iter().map(|(_, r)| r.rect.size()).collect();
+    /// });
+    /// assert_eq!(sizes[1] - sizes[0], vec2(20., 10.));
+    /// ```
+    pub fn set_padding(&mut self, padding: Option<Vec2>) -> &mut Self {
+        self.options.padding = padding;
+        self
+    }
+
     /// In what time should the toast expire? Set to `None` for no expiry.
     pub fn set_duration(&mut self, duration: Option<Duration>) -> &mut Self {
         if let Some(duration) = duration {
@@ -285,9 +1524,329 @@ impl Toast {
         self
     }
 
-    /// Dismiss this toast
+    /// Attach arbitrary user data to the toast, replacing any previous data.
+    /// ```
+    /// use egui_notify::Toast;
+    ///
+    /// let mut toast = Toast::basic("Saved");
+    /// toast.set_data(42u32);
+    /// assert_eq!(toast.data::<u32>(), Some(&42));
+    /// assert_eq!(toast.data::<String>(), None);
+    /// ```
+    pub fn set_data<T: Any + Send>(&mut self, data: T) -> &mut Self {
+        self.data = Some(ToastData(Box::new(data)));
+        self
+    }
+
+    /// User data attached to the toast, if it is of type `T`.
+    pub fn data<T: Any>(&self) -> Option<&T> {
+        self.data.as_ref()?.0.downcast_ref()
+    }
+
+    /// Mutable user data attached to the toast, if it is of type `T`.
+    pub fn data_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.data.as_mut()?.0.downcast_mut()
+    }
+
+    /// Removes the user data from the toast, if it is of type `T`.
+    pub fn take_data<T: Any>(&mut self) -> Option<T> {
+        match self.data.take()?.0.downcast() {
+            Ok(data) => Some(*data),
+            Err(data) => {
+                self.data = Some(ToastData(data));
+                None
+            }
+        }
+    }
+
+    /// Dismiss this toast. Does nothing if the toast is already being dismissed.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default()
+    ///     .suppress_initial_animation(true)
+    ///     .with_disappear_duration(Duration::from_secs(1));
+    /// t.info("Hello");
+    /// for frame in 0..=5 {
+    ///     let input = egui::RawInput { time: Some(frame as f64 * 0.1), ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    ///     if frame == 0 {
+    ///         t.iter_mut().next().unwrap().dismiss();
+    ///     }
+    /// }
+    /// let left = t.next_wake().unwrap();
+    /// assert!(left < Duration::from_millis(600));
+    ///
+    /// t.iter_mut().next().unwrap().dismiss();
+    /// assert_eq!(t.next_wake(), Some(left));
+    /// ```
     pub fn dismiss(&mut self) {
-        self.state = ToastState::Disapper;
+        if !self.state.disappearing() && !self.state.disappeared() {
+            self.state = ToastState::Disappear;
+        }
+    }
+
+    /// Size the toast would have when shown with the given padding, without painting it.
+    /// ```
+    /// use egui_notify::Toast;
+    ///
+    /// # egui_notify::__run_test_ctx(|ctx| {
+    /// let toast = Toast::basic("Hello, World!").info();
+    /// let small = toast.measure(ctx, egui::vec2(4., 4.));
+    /// let large = toast.measure(ctx, egui::vec2(20., 20.));
+    /// assert_eq!(large - small, egui::vec2(32., 32.));
+    /// # });
+    /// ```
+    pub fn measure(&self, ctx: &Context, padding: Vec2) -> Vec2 {
+        let visuals = ctx.style().visuals.widgets.noninteractive;
+        let mut layout = ToastLayout::new(
+            ctx,
+            self,
+            &visuals,
+            self.options.closable,
+            self.options.level.color(),
+        );
+        layout.fit_max_width(ctx, self, &visuals, padding);
+        layout.size(padding)
+    }
+
+    /// Dismiss this toast once `delay` has passed, regardless of its duration or hover state.
+    /// ```
+    /// use std::time::Duration;
+    /// use egui_notify::{ToastPhase, Toasts};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Undone").set_duration(None).dismiss_after(Duration::from_secs(1));
+    /// let mut phase_at = |t: &mut Toasts, time: f64| {
+    ///     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    ///     t.iter().next().unwrap().phase()
+    /// };
+    ///
+    /// assert_eq!(phase_at(&mut t, 0.), ToastPhase::Idle);
+    /// assert_eq!(phase_at(&mut t, 0.5), ToastPhase::Idle);
+    /// assert_eq!(phase_at(&mut t, 0.9), ToastPhase::Idle);
+    /// assert_eq!(phase_at(&mut t, 1.1), ToastPhase::Disappearing);
+    /// ```
+    pub fn dismiss_after(&mut self, delay: Duration) -> &mut Self {
+        self.dismiss_delay = Some(duration_to_seconds_f32(delay));
+        self
+    }
+
+    /// Dismiss this toast once `condition` returns `true`, e.g. when the state it reports on
+    /// changes. The condition is checked on every [`Toasts::show`](crate::Toasts::show).
+    /// ```
+    /// use egui_notify::Toasts;
+    /// use std::sync::{
+    ///     atomic::{AtomicBool, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// let connected = Arc::new(AtomicBool::new(false));
+    /// let mut t = Toasts::default();
+    /// let flag = connected.clone();
+    /// t.warning("Reconnecting...")
+    ///     .set_duration(None)
+    ///     .dismiss_when(move || flag.load(Ordering::Relaxed));
+    ///
+    /// egui_notify::__run_test_ctx(|ctx| t.show(ctx));
+    /// assert_eq!(t.debug_stats().disappearing, 0);
+    ///
+    /// connected.store(true, Ordering::Relaxed);
+    /// egui_notify::__run_test_ctx(|ctx| t.show(ctx));
+    /// assert_eq!(t.debug_stats().disappearing, 1);
+    /// ```
+    pub fn dismiss_when(&mut self, condition: impl Fn() -> bool + Send + 'static) -> &mut Self {
+        self.dismiss_condition = Some(DismissCondition(Box::new(condition)));
+        self
+    }
+
+    /// Calls `callback` once the toast is done disappearing, in the [`Toasts::show`] call its
+    /// exit animation finishes. The toast is removed in the next call. Toasts removed without
+    /// animating, like with [`Toasts::dismiss_all_immediate`], call it when they're removed.
+    /// ```
+    /// use egui_notify::{ToastPhase, Toasts};
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let mut frame = |t: &mut Toasts| {
+    ///     let input = egui::RawInput { predicted_dt: 0.1, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| t.show(ctx));
+    /// };
+    ///
+    /// let calls = Arc::new(AtomicUsize::new(0));
+    /// let counter = calls.clone();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Bye").on_disappeared(move |_| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    /// frame(&mut t);
+    /// t.dismiss_all_toasts();
+    /// while t.iter().next().unwrap().phase() == ToastPhase::Disappearing {
+    ///     assert_eq!(calls.load(Ordering::Relaxed), 0);
+    ///     frame(&mut t);
+    /// }
+    /// assert_eq!(calls.load(Ordering::Relaxed), 1);
+    /// assert_eq!(t.len(), 1);
+    ///
+    /// frame(&mut t);
+    /// assert!(t.is_empty());
+    /// assert_eq!(calls.load(Ordering::Relaxed), 1);
+    /// ```
+    ///
+    /// [`Toasts::show`]: crate::Toasts::show
+    /// [`Toasts::dismiss_all_immediate`]: crate::Toasts::dismiss_all_immediate
+    pub fn on_disappeared(&mut self, callback: impl FnOnce(&Toast) + Send + 'static) -> &mut Self {
+        self.on_disappeared = Some(DisappearCallback(Box::new(callback)));
+        self
+    }
+
+    /// Run `callback` whenever the body of the toast is clicked, e.g. to open the log an error
+    /// came from. Clicks on the closing cross don't count, and neither do clicks on a
+    /// disappearing toast. A click is a press and a release both over the body.
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use egui::{Event, PointerButton, Pos2};
+    /// use egui_notify::Toasts;
+    ///
+    /// let clicks = Arc::new(AtomicUsize::new(0));
+    /// let counter = clicks.clone();
+    /// let ctx = egui::Context::default();
+    /// let mut t = Toasts::default().suppress_initial_animation(true);
+    /// t.info("Hello").on_click(move || {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    ///
+    /// let mut frame = |t: &mut Toasts, events: Vec<Event>| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let mut rect = egui::Rect::NOTHING;
+    ///     let _ = ctx.run(input, |ctx| rect = t.show_and_interact(ctx)[0].1.rect);
+    ///     rect
+    /// };
+    /// let button = |pos: Pos2, pressed| Event::PointerButton {
+    ///     pos,
+    ///     button: PointerButton::Primary,
+    ///     pressed,
+    ///     modifiers: Default::default(),
+    /// };
+    /// let rect = frame(&mut t, vec![]);
+    /// let body = rect.left_center() + egui::vec2(40., 0.);
+    /// let cross = rect.right_center() - egui::vec2(20., 0.);
+    ///
+    /// frame(&mut t, vec![Event::PointerMoved(body), button(body, true)]);
+    /// frame(&mut t, vec![button(body, false)]);
+    /// frame(&mut t, vec![]);
+    /// frame(&mut t, vec![]);
+    /// assert_eq!(clicks.load(Ordering::Relaxed), 1);
+    ///
+    /// // Pressing elsewhere and releasing over the body isn't a click
+    /// frame(&mut t, vec![Event::PointerMoved(cross), button(cross, true)]);
+    /// frame(&mut t, vec![Event::PointerMoved(body), button(body, false)]);
+    /// assert_eq!(clicks.load(Ordering::Relaxed), 1);
+    ///
+    /// frame(&mut t, vec![Event::PointerMoved(cross), button(cross, true), button(cross, false)]);
+    /// frame(&mut t, vec![]);
+    /// assert_eq!(clicks.load(Ordering::Relaxed), 1);
+    /// assert_eq!(t.debug_stats().disappearing, 1);
+    /// ```
+    pub fn on_click(&mut self, callback: impl FnMut() + Send + 'static) -> &mut Self {
+        self.on_click = Some(ClickCallback(Box::new(callback)));
+        self
+    }
+
+    /// Marks the toast as disappeared, calling its disappear callback if it hasn't been yet.
+    pub(crate) fn finish_disappearing(&mut self) {
+        self.state = ToastState::Disappeared;
+        if let Some(callback) = self.on_disappeared.take() {
+            (callback.0)(self);
+        }
+    }
+
+    /// Keep the toast for as long as the [`egui::Window`] with the id `window_id` is open,
+    /// e.g. for help tied to a dialog. The toast no longer expires by itself, and is dismissed
+    /// once [`Toasts::set_window_open`](crate::Toasts::set_window_open) reports the window
+    /// closed. Whether the window is covered by other layers doesn't matter.
+    /// ```
+    /// use egui_notify::Toasts;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let window_id = egui::Id::new("Import");
+    /// let mut t = Toasts::default();
+    /// let mut frame = |t: &mut Toasts, mut open: bool, covered: bool| {
+    ///     let _ = ctx.run(Default::default(), |ctx| {
+    ///         egui::Window::new("Import").open(&mut open).show(ctx, |ui| {
+    ///             ui.label("Pick a file");
+    ///         });
+    ///         if covered {
+    ///             egui::Area::new("modal")
+    ///                 .order(egui::Order::Foreground)
+    ///                 .fixed_pos(egui::Pos2::ZERO)
+    ///                 .show(ctx, |ui| ui.allocate_space(ui.ctx().screen_rect().size()));
+    ///         }
+    ///         t.set_window_open(window_id, open);
+    ///         t.show(ctx);
+    ///     });
+    /// };
+    ///
+    /// frame(&mut t, true, false);
+    /// t.info("Files can be dropped too").pin_to_window(window_id);
+    /// for _ in 0..3 {
+    ///     frame(&mut t, true, true);
+    /// }
+    /// assert_eq!(t.debug_stats().disappearing, 0);
+    ///
+    /// for _ in 0..3 {
+    ///     frame(&mut t, false, false);
+    /// }
+    /// assert_eq!(t.debug_stats().disappearing, 1);
+    /// ```
+    pub fn pin_to_window(&mut self, window_id: Id) -> &mut Self {
+        self.pinned_window = Some(window_id);
+        self.options.duration = None;
+        self
+    }
+
+    /// Takes the contents of `other`, keeping this toast's identity and animation state.
+    pub(crate) fn update_from(&mut self, other: Toast) {
+        *self = Self {
+            height: self.height,
+            width: self.width,
+            toast_hovered: self.toast_hovered,
+            cross_hovered: self.cross_hovered,
+            action_hovered: self.action_hovered,
+            timestamp: self.timestamp,
+            add_index: self.add_index,
+            state: std::mem::replace(&mut self.state, ToastState::Appear),
+            value: self.value,
+            shown_for: self.shown_for,
+            ..other
+        };
+    }
+
+    /// Advances the appear/disappear animation by `dt` seconds, taking the
+    /// `(appear, disappear)` duration in seconds from end to end, returning whether it's
+    /// still running.
+    pub(crate) fn animate(&mut self, dt: f32, durations: (f32, f32)) -> bool {
+        if self.state.appearing() {
+            self.value = step_value(self.value, dt, durations.0);
+            if self.value >= 1. {
+                self.state = ToastState::Idle;
+            }
+        } else if self.state.disappearing() {
+            self.value = 1. - step_value(1. - self.value, dt, durations.1);
+            if self.value <= 0. {
+                self.finish_disappearing();
+            }
+        }
+        self.state.appearing() || self.state.disappearing()
     }
 
     pub(crate) fn size(&self) -> Vec2 {